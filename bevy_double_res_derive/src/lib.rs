@@ -0,0 +1,148 @@
+//! # Derive macro for per-field double-buffering with [bevy_double_res](https://docs.rs/bevy_double_res)
+//!
+//! Use it through **derive** feature of **bevy_double_res** crate
+
+#![warn(missing_docs)]
+
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Field, Fields};
+
+/// Generates companion type double-buffering only fields marked with `#[double]`
+///
+/// For struct **Name** it generates:
+/// * **NameDouble** - same struct, but every `#[double]` field is wrapped into `DoubleBuffer`,
+///   while other fields are stored once and shared between current and next states
+/// * **NameDoubleCurrent** - readonly references to current copies and shared fields
+/// * **NameDoubleNext** - mutable references to next copies
+///
+/// **NameDouble** provides `swap()` swapping every buffered field, `apply()` operating on
+/// current and next states at once, and conversion from **Name** cloning buffered fields
+///
+/// ```ignore
+/// #[derive(DoubleBuffered)]
+/// struct Boid {
+///     #[double]
+///     position: Vec2,
+///     mass: f32,
+/// }
+///
+/// let mut boid = BoidDouble::from(Boid { position: Vec2::ZERO, mass: 1. });
+///
+/// boid.apply(|current, next| *next.position = *current.position + Vec2::X * *current.mass);
+/// boid.swap();
+/// ```
+#[proc_macro_derive(DoubleBuffered, attributes(double))]
+pub fn derive_double_buffered(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "DoubleBuffered does not support generic structs",
+        ));
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "DoubleBuffered requires struct with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "DoubleBuffered can only be derived for structs",
+            ))
+        }
+    };
+
+    let (buffered, shared): (Vec<&Field>, Vec<&Field>) = fields
+        .iter()
+        .partition(|field| field.attrs.iter().any(|attr| attr.path.is_ident("double")));
+
+    if buffered.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "DoubleBuffered requires at least one field marked with #[double]",
+        ));
+    }
+
+    let buffered_vis: Vec<_> = buffered.iter().map(|field| &field.vis).collect();
+    let buffered_names: Vec<_> = buffered.iter().map(|field| &field.ident).collect();
+    let buffered_types: Vec<_> = buffered.iter().map(|field| &field.ty).collect();
+
+    let shared_vis: Vec<_> = shared.iter().map(|field| &field.vis).collect();
+    let shared_names: Vec<_> = shared.iter().map(|field| &field.ident).collect();
+    let shared_types: Vec<_> = shared.iter().map(|field| &field.ty).collect();
+
+    let vis = &input.vis;
+    let name = &input.ident;
+    let double = format_ident!("{}Double", name);
+    let current = format_ident!("{}DoubleCurrent", name);
+    let next = format_ident!("{}DoubleNext", name);
+
+    Ok(quote! {
+        #[doc = concat!(
+            "Companion of [`", stringify!(#name), "`] double-buffering fields marked with `#[double]`"
+        )]
+        #[allow(missing_docs)]
+        #vis struct #double {
+            #( #buffered_vis #buffered_names: ::bevy_double_res::DoubleBuffer<#buffered_types>, )*
+            #( #shared_vis #shared_names: #shared_types, )*
+        }
+
+        #[doc = concat!("Current state of [`", stringify!(#double), "`]")]
+        #[allow(missing_docs)]
+        #vis struct #current<'a> {
+            #( #buffered_vis #buffered_names: &'a #buffered_types, )*
+            #( #shared_vis #shared_names: &'a #shared_types, )*
+        }
+
+        #[doc = concat!("Next state of [`", stringify!(#double), "`]")]
+        #[allow(missing_docs)]
+        #vis struct #next<'a> {
+            #( #buffered_vis #buffered_names: &'a mut #buffered_types, )*
+        }
+
+        impl #double {
+            /// Swaps every buffered field
+            pub fn swap(&mut self) {
+                #( self.#buffered_names.swap(); )*
+            }
+
+            /// Applies function to operate on current and next states
+            pub fn apply<Res>(&mut self, f: impl FnOnce(#current<'_>, #next<'_>) -> Res) -> Res {
+                #( let #buffered_names = self.#buffered_names.split_ordered(); )*
+                f(
+                    #current {
+                        #( #buffered_names: #buffered_names.0, )*
+                        #( #shared_names: &self.#shared_names, )*
+                    },
+                    #next {
+                        #( #buffered_names: #buffered_names.1, )*
+                    },
+                )
+            }
+        }
+
+        impl ::core::convert::From<#name> for #double {
+            fn from(value: #name) -> Self {
+                Self {
+                    #( #buffered_names: ::bevy_double_res::DoubleBuffer::new(value.#buffered_names), )*
+                    #( #shared_names: value.#shared_names, )*
+                }
+            }
+        }
+    })
+}
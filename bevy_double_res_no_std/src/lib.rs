@@ -0,0 +1,33 @@
+//! Checks that buffers build and work with `no_std` active
+//!
+//! Crate is excluded from the workspace, so features of **bevy_double_res** are never unified
+//! with the ones enabled by other members. Build it for a target without `std`, which fails if
+//! anything pulls `std` in, and run its test on host:
+//!
+//! ```sh
+//! cargo build --manifest-path bevy_double_res_no_std/Cargo.toml --target thumbv7em-none-eabihf
+//! cargo test --manifest-path bevy_double_res_no_std/Cargo.toml
+//! ```
+
+#![no_std]
+
+use bevy_double_res::{DoubleBuffer, IntoDoubleBuffer};
+
+/// Swap items of **pair** through [DoubleBuffer]
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(bevy_double_res_no_std::swap_pair((10, 20)), (20, 10));
+/// ```
+pub fn swap_pair(pair: (i32, i32)) -> (i32, i32) {
+    let mut tuple: DoubleBuffer<(i32, i32)> = pair.into_double_buf();
+
+    tuple.apply(|current, next| {
+        next.0 = current.1;
+        next.1 = current.0;
+    });
+    tuple.swap();
+
+    *tuple.current()
+}
@@ -17,9 +17,9 @@ fn setup(
     mut meshes: ResMut<Assets<Mesh>>,
     mut colors: ResMut<Assets<ColorMaterial>>,
 ) {
-    commands.spawn_bundle(Camera2dBundle::default());
+    commands.spawn(Camera2dBundle::default());
     commands
-        .spawn_bundle(ColorMesh2dBundle {
+        .spawn(ColorMesh2dBundle {
             mesh: meshes.add(shape::Quad::new(Vec2::splat(60.)).into()).into(),
             material: colors.add(Color::RED.into()),
             transform: Transform::from_xyz(-100., 0., 0.),
@@ -27,7 +27,7 @@ fn setup(
         })
         .insert(FirstMarker);
     commands
-        .spawn_bundle(ColorMesh2dBundle {
+        .spawn(ColorMesh2dBundle {
             mesh: meshes.add(shape::Quad::new(Vec2::splat(60.)).into()).into(),
             material: colors.add(Color::BLUE.into()),
             transform: Transform::from_xyz(100., 0., 0.),
@@ -35,7 +35,7 @@ fn setup(
         })
         .insert(SecondMarker);
     commands
-        .spawn_bundle(ColorMesh2dBundle {
+        .spawn(ColorMesh2dBundle {
             mesh: meshes.add(shape::Quad::new(Vec2::splat(60.)).into()).into(),
             material: colors.add(Color::GREEN.into()),
             transform: Transform::from_xyz(0., 100., 0.),
@@ -0,0 +1,62 @@
+//! Provides thread-safe double buffer publishing states with atomic pointer swap
+
+use arc_swap::ArcSwap;
+use std::sync::Arc;
+
+/// Double buffer shared between threads, where producer publishes next state without `&mut`
+///
+/// Readers get [Arc] of current state, which stays valid and unchanged even if a newer one is
+/// published meanwhile. Unlike [DoubleBuffer](crate::DoubleBuffer), both reading and publishing
+/// take `&self`, so buffer can be shared e.g. between simulation and render extraction threads
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "atomic")]
+/// # {
+/// use bevy_double_res::AtomicDoubleBuffer;
+/// use std::sync::Arc;
+///
+/// let buffer = AtomicDoubleBuffer::new((0, 0));
+///
+/// std::thread::scope(|scope| {
+///     scope.spawn(|| {
+///         for i in 1..=100 {
+///             buffer.publish(Arc::new((i, -i)));
+///         }
+///     });
+///
+///     scope.spawn(|| {
+///         for _ in 0..100 {
+///             let current = buffer.current();
+///             assert_eq!(current.0, -current.1);
+///         }
+///     });
+/// });
+///
+/// assert_eq!(*buffer.current(), (100, -100));
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct AtomicDoubleBuffer<T> {
+    current: ArcSwap<T>,
+}
+
+impl<T> AtomicDoubleBuffer<T> {
+    /// Create buffer with **value** as current state
+    pub fn new(value: T) -> Self {
+        Self {
+            current: ArcSwap::from_pointee(value),
+        }
+    }
+
+    /// Get current state
+    pub fn current(&self) -> Arc<T> {
+        self.current.load_full()
+    }
+
+    /// Replace current state with **value**, readers holding previous state keep it alive
+    pub fn publish(&self, value: Arc<T>) {
+        self.current.store(value);
+    }
+}
@@ -0,0 +1,77 @@
+//! Provides [AutoSwap] system parameter, swapping buffer resource when system finishes
+
+use crate::DoubleResMut;
+use bevy_ecs::system::SystemParam;
+use std::marker::PhantomData;
+
+/// Wrapper of [DoubleResMut<T>] which swaps buffer when system finishes
+///
+/// Swap happens exactly once per system run, so there is no need to call it manually
+///
+/// # Example
+///
+/// ```
+/// use bevy::ecs::schedule::Stage;
+/// use bevy::prelude::*;
+/// use bevy_double_res::prelude::*;
+///
+/// fn circular_dependent_system(mut tuple: AutoSwap<(i32, i32)>) {
+///     tuple.apply(|current, next| {
+///         next.0 = current.1;
+///         next.1 = current.0;
+///     });
+/// }
+///
+/// let mut world = World::new();
+/// world.insert_resource((10, 20).into_double_buf());
+///
+/// let mut stage = SystemStage::single_threaded().with_system(circular_dependent_system);
+///
+/// stage.run(&mut world);
+///
+/// assert_eq!(world.resource::<DoubleBuffer<(i32, i32)>>().index(), 1);
+/// assert_eq!(world.resource::<DoubleBuffer<(i32, i32)>>().current(), &(20, 10));
+///
+/// stage.run(&mut world);
+///
+/// assert_eq!(world.resource::<DoubleBuffer<(i32, i32)>>().index(), 0);
+/// assert_eq!(world.resource::<DoubleBuffer<(i32, i32)>>().current(), &(10, 20));
+/// ```
+#[derive(SystemParam)]
+pub struct AutoSwap<'w, 's, T>
+where
+    T: Send + Sync + 'static,
+{
+    buffer: DoubleResMut<'w, T>,
+    #[system_param(ignore)]
+    marker: PhantomData<&'s ()>,
+}
+
+impl<'w, 's, T> AutoSwap<'w, 's, T>
+where
+    T: Send + Sync + 'static,
+{
+    /// Same as [`DoubleBuffer::current`](crate::DoubleBuffer::current)
+    pub fn current(&self) -> &T {
+        self.buffer.current()
+    }
+
+    /// Same as [`DoubleBuffer::next`](crate::DoubleBuffer::next)
+    pub fn next(&self) -> &T {
+        self.buffer.next()
+    }
+
+    /// Same as [`DoubleBuffer::apply`](crate::DoubleBuffer::apply)
+    pub fn apply<Res>(&mut self, f: impl FnOnce(&T, &mut T) -> Res) -> Res {
+        self.buffer.apply(f)
+    }
+}
+
+impl<'w, 's, T> Drop for AutoSwap<'w, 's, T>
+where
+    T: Send + Sync + 'static,
+{
+    fn drop(&mut self) {
+        self.buffer.swap();
+    }
+}
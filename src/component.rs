@@ -0,0 +1,85 @@
+//! Provides per-entity double-buffering with [DoubleComponent]
+
+use crate::DoubleBuffer;
+use bevy_ecs::prelude::*;
+use std::ops::{Deref, DerefMut};
+
+/// [Component] wrapper of [DoubleBuffer<T>]
+///
+/// Dereferences to underlying buffer, so it is used the same way as buffer resources
+///
+/// # Example
+///
+/// ```
+/// use bevy::ecs::schedule::Stage;
+/// use bevy::prelude::*;
+/// use bevy_double_res::prelude::*;
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Velocity(f32);
+///
+/// fn circular_dependent_system(mut query: Query<&mut DoubleComponent<Velocity>>) {
+///     for mut velocity in query.iter_mut() {
+///         velocity.apply(|current, next| next.0 = current.0 * 2.);
+///         velocity.swap();
+///     }
+/// }
+///
+/// let mut world = World::new();
+/// let boid = world.spawn(DoubleComponent::from(Velocity(1.).into_double_buf())).id();
+///
+/// let mut stage = SystemStage::single_threaded().with_system(circular_dependent_system);
+///
+/// stage.run(&mut world);
+///
+/// let velocity = world.get::<DoubleComponent<Velocity>>(boid).unwrap();
+///
+/// assert_eq!(velocity.current(), &Velocity(2.));
+/// assert_eq!(velocity.index(), 1);
+/// ```
+#[derive(Component, Debug)]
+pub struct DoubleComponent<T>(pub DoubleBuffer<T>);
+
+/// Alias for [Query] of readonly [DoubleComponent<T>]
+///
+/// # Example
+///
+/// ```
+/// use bevy_double_res::DoubleQuery;
+/// fn display_system(double_components: DoubleQuery<(u8, u8, u8)>) {
+///     // ...
+/// }
+/// ```
+pub type DoubleQuery<'w, 's, T, F = ()> = Query<'w, 's, &'static DoubleComponent<T>, F>;
+
+/// Alias for [Query] of mutable [DoubleComponent<T>]
+///
+/// # Example
+///
+/// ```
+/// use bevy_double_res::DoubleQueryMut;
+/// fn circular_dependent_system(mut double_components: DoubleQueryMut<(u8, u8, u8)>) {
+///     // ...
+/// }
+/// ```
+pub type DoubleQueryMut<'w, 's, T, F = ()> = Query<'w, 's, &'static mut DoubleComponent<T>, F>;
+
+impl<T> From<DoubleBuffer<T>> for DoubleComponent<T> {
+    fn from(buffer: DoubleBuffer<T>) -> Self {
+        Self(buffer)
+    }
+}
+
+impl<T> Deref for DoubleComponent<T> {
+    type Target = DoubleBuffer<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for DoubleComponent<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
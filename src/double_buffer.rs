@@ -2,6 +2,10 @@
 //!
 //! Implementation uses two separate copies and current copy index
 
+use crate::{MultiBuffer, SwapStrategy};
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+
 /// Enables double-buffering of your data by storing two separate copies and current copy index
 ///
 /// # Example
@@ -31,14 +35,10 @@
 /// assert_eq!(tuple.current(), &(10, 20));
 /// assert_eq!(tuple.next(), &(20, 10));
 /// ```
-#[derive(Debug)]
-pub struct DoubleBuffer<T>
-where
-    T: Sized,
-{
-    buffer: [T; 2],
-    index: u8,
-}
+///
+/// This is a [MultiBuffer] of two copies, specialized with [`DoubleBuffer::next`] and
+/// [`DoubleBuffer::swap`]
+pub type DoubleBuffer<T> = MultiBuffer<T, 2>;
 
 impl<T> DoubleBuffer<T> {
     /// Construct buffer manually using two copies and index
@@ -52,139 +52,201 @@ impl<T> DoubleBuffer<T> {
     /// assert_eq!(tuple.current(), &(10, 20));
     /// ```
     pub fn from_buffer(buffer: [T; 2], index: u8) -> Self {
-        Self { buffer, index }
+        Self::from_array(buffer, index.into())
     }
 
-    /// Access underlying buffer for reading
+    /// Construct buffer manually using two copies and index in const context
+    ///
+    /// Same as [`DoubleBuffer::from_buffer`], but usable for `const` and `static` items
     ///
     /// # Example
     ///
     /// ```
     /// use bevy_double_res::DoubleBuffer;
-    /// let tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    /// const TABLE: DoubleBuffer<u32> = DoubleBuffer::from_buffer_const([10, 20], 1);
     ///
-    /// assert_eq!(tuple.buffer(), &[(10, 20), (20, 10)]);
+    /// assert_eq!(TABLE.current(), &20);
+    /// assert_eq!(TABLE.next(), &10);
     /// ```
-    pub fn buffer(&self) -> &[T; 2] {
-        &self.buffer
+    pub const fn from_buffer_const(buffer: [T; 2], index: u8) -> Self {
+        Self {
+            buffer,
+            index: index as usize,
+            generation: 0,
+            swap_baseline: 0,
+            dirty: false,
+            pending_swap: false,
+            labels: None,
+        }
     }
 
-    /// Access underlying buffer for mutation
+    /// Construct buffer manually using two copies, index and
+    /// [generation](crate::MultiBuffer::generation)
+    ///
+    /// [`DoubleBuffer::from_buffer`] starts generation from **0**, this one allows to restore
+    /// it, e.g. after loading saved state
     ///
     /// # Example
     ///
     /// ```
     /// use bevy_double_res::DoubleBuffer;
-    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    /// let mut tuple = DoubleBuffer::from_buffer_with_generation([(10, 20), (20, 10)], 1, 41);
     ///
-    /// tuple.buffer_mut()[0] = (30, 40);
+    /// tuple.swap();
     ///
-    /// assert_eq!(tuple.buffer(), &[(30, 40), (20, 10)]);
+    /// assert_eq!(tuple.current(), &(10, 20));
+    /// assert_eq!(tuple.generation(), 42);
     /// ```
-    pub fn buffer_mut(&mut self) -> &mut [T; 2] {
-        &mut self.buffer
+    pub fn from_buffer_with_generation(buffer: [T; 2], index: u8, generation: u64) -> Self {
+        Self {
+            buffer,
+            index: index.into(),
+            generation,
+            swap_baseline: generation,
+            dirty: false,
+            pending_swap: false,
+            labels: None,
+        }
     }
 
-    /// Access underlying current copy index
+    /// Construct buffer manually using two copies, index will be **0**
+    ///
+    /// Chain with [`DoubleBuffer::with_index`] to choose another current copy
     ///
     /// # Example
     ///
     /// ```
     /// use bevy_double_res::DoubleBuffer;
-    /// let mut tuple = DoubleBuffer::<()>::default();
-    ///
-    /// assert_eq!(tuple.index(), 0);
-    ///
-    /// tuple.swap();
+    /// let tuple = DoubleBuffer::from_slots([(10, 20), (20, 10)]).with_index(1);
     ///
+    /// assert_eq!(tuple.current(), &(20, 10));
     /// assert_eq!(tuple.index(), 1);
-    ///
-    /// tuple.swap();
-    ///
-    /// assert_eq!(tuple.index(), 0);
     /// ```
-    ///
-    /// Default value is always zero
-    pub fn index(&self) -> u8 {
-        self.index
+    pub fn from_slots(buffer: [T; 2]) -> Self {
+        Self::from_buffer(buffer, 0)
     }
 
-    /// Set underlying current copy index
+    /// Set underlying current copy index, consuming and returning the buffer
     ///
     /// # Example
     ///
     /// ```
     /// use bevy_double_res::DoubleBuffer;
-    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    /// let tuple = DoubleBuffer::from_slots([(10, 20), (20, 10)]).with_index(0);
     ///
-    /// tuple.set_index(1);
-    ///
-    /// assert_eq!(tuple.current(), &(20, 10));
+    /// assert_eq!(tuple.current(), &(10, 20));
     /// ```
     ///
-    /// Setting index outside of range \[0, 1] and then getting the value is likely a panic
-    pub fn set_index(&mut self, value: u8) {
-        self.index = value;
+    /// # Panics
+    ///
+    /// Panics if **index** is outside of range \[0, 1], same as [`DoubleBuffer::swap_to`]
+    ///
+    /// ```should_panic
+    /// use bevy_double_res::DoubleBuffer;
+    /// let tuple = DoubleBuffer::from_slots([(10, 20), (20, 10)]).with_index(2);
+    /// ```
+    pub fn with_index(mut self, index: u8) -> Self {
+        self.swap_to(index);
+        self
     }
 
-    /// Get readonly copy reference under current index
+    /// Create double buffer of **T** from two distinct copies of **T**
+    ///
+    /// First copy will be current and index will be **0**. Unlike [`DoubleBuffer::new`],
+    /// **T** is not required to be cloneable
     ///
     /// # Example
     ///
     /// ```
     /// use bevy_double_res::DoubleBuffer;
-    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    /// let tuple = DoubleBuffer::new_with((10, 20), (20, 10));
     ///
     /// assert_eq!(tuple.current(), &(10, 20));
-    ///
-    /// tuple.swap();
-    ///
-    /// assert_eq!(tuple.current(), &(20, 10));
+    /// assert_eq!(tuple.next(), &(20, 10));
+    /// assert_eq!(tuple.index(), 0);
     /// ```
-    pub fn current(&self) -> &T {
-        &self.buffer[self.index as usize]
+    pub fn new_with(first: T, second: T) -> Self {
+        Self::from_buffer([first, second], 0)
     }
 
-    /// Get mutable copy reference under current index
+    /// Create double buffer with **value** as current copy and **fallback** as next copy
+    ///
+    /// Same as [`DoubleBuffer::new_with`], but names the intent of next copy starting from
+    /// neutral state. **T** is not required to be cloneable
     ///
     /// # Example
     ///
     /// ```
     /// use bevy_double_res::DoubleBuffer;
-    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
     ///
-    /// tuple.current_mut().0 = 999;
+    /// #[derive(Debug, PartialEq)]
+    /// struct Samples(Vec<f32>);
     ///
-    /// assert_eq!(tuple.current(), &(999, 20));
+    /// let tuple = DoubleBuffer::new_or(Samples(vec![0.5, 1.0]), Samples(Vec::new()));
     ///
-    /// tuple.swap();
+    /// assert_eq!(tuple.current(), &Samples(vec![0.5, 1.0]));
+    /// assert_eq!(tuple.next(), &Samples(Vec::new()));
+    /// assert_eq!(tuple.index(), 0);
+    /// ```
+    pub fn new_or(value: T, fallback: T) -> Self {
+        Self::new_with(value, fallback)
+    }
+
+    /// Destruct buffer into two copies and index
     ///
-    /// assert_eq!(tuple.current(), &(20, 10));
+    /// Reverse of [`DoubleBuffer::from_buffer`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 1);
+    ///
+    /// assert_eq!(tuple.into_inner(), ([(10, 20), (20, 10)], 1));
     /// ```
-    pub fn current_mut(&mut self) -> &mut T {
-        &mut self.buffer[self.index as usize]
+    pub fn into_inner(self) -> ([T; 2], u8) {
+        let index = self.index();
+        (self.buffer, index)
     }
 
-    /// Get readonly copy reference under opposite of current index
+    /// Destruct buffer into current copy, dropping next one
     ///
     /// # Example
     ///
     /// ```
     /// use bevy_double_res::DoubleBuffer;
     /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    /// tuple.swap();
     ///
-    /// assert_eq!(tuple.next(), &(20, 10));
+    /// assert_eq!(tuple.collapse(), (20, 10));
+    /// ```
     ///
-    /// tuple.swap();
+    /// # Panics
     ///
-    /// assert_eq!(tuple.current(), &(20, 10));
-    /// assert_eq!(tuple.next(), &(10, 20));
+    /// Panics if index was set outside of range \[0, 1] with [`DoubleBuffer::set_index`]
+    ///
+    /// ```should_panic
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    ///
+    /// tuple.set_index(2);
+    /// tuple.collapse();
     /// ```
-    pub fn next(&self) -> &T {
-        &self.buffer[1 - self.index as usize]
+    pub fn collapse(self) -> T {
+        let [first, second] = self.buffer;
+        match self.index {
+            0 => first,
+            1 => second,
+            index => panic!("{}", IndexOutOfRange(index)),
+        }
     }
 
-    /// Get mutable copy reference under opposite of current index
+    /// Overwrite both copies and index in place, returning the old ones
+    ///
+    /// Same as [`DoubleBuffer::from_buffer`] followed by [`DoubleBuffer::into_inner`] of the old
+    /// buffer, except [generation](crate::MultiBuffer::generation),
+    /// [swap counter](crate::MultiBuffer::swaps_since_reset),
+    /// [slot labels](DoubleBuffer::set_slot_labels) are kept
     ///
     /// # Example
     ///
@@ -192,44 +254,50 @@ impl<T> DoubleBuffer<T> {
     /// use bevy_double_res::DoubleBuffer;
     /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
     ///
-    /// tuple.next_mut().0 = 999;
+    /// tuple.set_slot_labels("front", "back");
+    /// tuple.swap();
     /// tuple.swap();
     ///
-    /// assert_eq!(tuple.current(), &(999, 10));
-    /// assert_eq!(tuple.next(), &(10, 20));
+    /// let old = tuple.replace_buffer([(30, 40), (40, 30)], 1);
+    ///
+    /// assert_eq!(old, ([(10, 20), (20, 10)], 0));
+    /// assert_eq!(tuple.current(), &(40, 30));
+    /// assert_eq!(tuple.slot_label(), "back");
+    /// assert_eq!(tuple.swaps_since_reset(), 2);
     /// ```
-    pub fn next_mut(&mut self) -> &mut T {
-        &mut self.buffer[1 - self.index as usize]
+    pub fn replace_buffer(&mut self, buffer: [T; 2], index: u8) -> ([T; 2], u8) {
+        let mut replacement = self.with_meta_from(buffer);
+        replacement.index = index.into();
+        replacement.dirty = true;
+        replacement.pending_swap = self.pending_swap;
+        core::mem::replace(self, replacement).into_inner()
     }
 
-    /// Toggles current index between **0** and **1**
+    /// Access underlying current copy index
     ///
     /// # Example
     ///
     /// ```
     /// use bevy_double_res::DoubleBuffer;
-    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    /// let mut tuple = DoubleBuffer::<()>::default();
     ///
-    /// assert_eq!(tuple.current(), &(10, 20));
     /// assert_eq!(tuple.index(), 0);
     ///
     /// tuple.swap();
     ///
-    /// assert_eq!(tuple.current(), &(20, 10));
     /// assert_eq!(tuple.index(), 1);
     ///
     /// tuple.swap();
     ///
-    /// assert_eq!(tuple.current(), &(10, 20));
     /// assert_eq!(tuple.index(), 0);
     /// ```
-    pub fn swap(&mut self) {
-        self.index = 1 - self.index;
+    ///
+    /// Default value is always zero
+    pub fn index(&self) -> u8 {
+        self.index as u8
     }
 
-    /// Returns two readonly references to copies
-    ///
-    /// Order does **not** depend on current index!
+    /// Set underlying current copy index
     ///
     /// # Example
     ///
@@ -237,21 +305,18 @@ impl<T> DoubleBuffer<T> {
     /// use bevy_double_res::DoubleBuffer;
     /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
     ///
-    /// assert_eq!(tuple.split(), (&(10, 20), &(20, 10)));
-    ///
-    /// tuple.swap();
+    /// tuple.set_index(1);
     ///
-    /// // Same as before!
-    /// assert_eq!(tuple.split(), (&(10, 20), &(20, 10)));
+    /// assert_eq!(tuple.current(), &(20, 10));
     /// ```
-    pub fn split(&self) -> (&T, &T) {
-        let (first, second) = self.buffer.split_at(1);
-        (&first[0], &second[0])
+    ///
+    /// Setting index outside of range \[0, 1] and then getting the value is a panic, see
+    /// [`DoubleBuffer::swap_to`] for version checking index right away
+    pub fn set_index(&mut self, value: u8) {
+        self.index = value.into();
     }
 
-    /// Returns two mutable references to copies
-    ///
-    /// Order does **not** depend on current index!
+    /// Set underlying current copy index, checking that it is in range \[0, 1]
     ///
     /// # Example
     ///
@@ -259,155 +324,1962 @@ impl<T> DoubleBuffer<T> {
     /// use bevy_double_res::DoubleBuffer;
     /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
     ///
-    /// assert_eq!(tuple.split_mut(), (&mut (10, 20), &mut (20, 10)));
+    /// tuple.swap_to(1);
     ///
-    /// tuple.swap();
+    /// assert_eq!(tuple.current(), &(20, 10));
+    /// ```
     ///
-    /// // Same as before!
-    /// assert_eq!(tuple.split_mut(), (&mut (10, 20), &mut (20, 10)));
+    /// # Panics
+    ///
+    /// Panics if index is outside of range \[0, 1]
+    ///
+    /// ```should_panic
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    ///
+    /// tuple.swap_to(2);
     /// ```
-    pub fn split_mut(&mut self) -> (&mut T, &mut T) {
-        let (first, second) = self.buffer.split_at_mut(1);
-        (&mut first[0], &mut second[0])
+    pub fn swap_to(&mut self, index: u8) {
+        if let Err(err) = self.try_swap_to(index) {
+            panic!("{}", err);
+        }
     }
 
-    /// Returns two references to copies
+    /// Set underlying current copy index, returning an error if it is outside of range \[0, 1]
     ///
-    /// Order **does** depend on current index and is:
-    /// 1. **current** - immutable reference
-    /// 2. **next** - mutable reference
+    /// Index is left unchanged on error
     ///
     /// # Example
     ///
     /// ```
-    /// use bevy_double_res::DoubleBuffer;
-    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (0, 0)], 0);
+    /// use bevy_double_res::{DoubleBuffer, IndexOutOfRange};
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
     ///
-    /// let (current, next) = tuple.split_ordered();
+    /// assert_eq!(tuple.try_swap_to(1), Ok(()));
+    /// assert_eq!(tuple.try_swap_to(2), Err(IndexOutOfRange(2)));
     ///
-    /// assert_eq!(current, &(10, 20));
-    /// assert_eq!(next, &mut (0, 0));
+    /// assert_eq!(tuple.index(), 1);
+    /// ```
+    pub fn try_swap_to(&mut self, index: u8) -> Result<(), IndexOutOfRange> {
+        if index > 1 {
+            return Err(IndexOutOfRange(index.into()));
+        }
+        self.index = index.into();
+        Ok(())
+    }
+
+    /// Get current copy index as [Side]
     ///
-    /// next.0 = current.1;
-    /// next.1 = current.0;
+    /// Index out of range, e.g. after [`DoubleBuffer::set_index`], is a panic in debug builds, same
+    /// as [`DoubleBuffer::current`](crate::MultiBuffer::current)
     ///
-    /// tuple.swap();
+    /// # Example
     ///
-    /// let (current, next) = tuple.split_ordered();
+    /// ```
+    /// use bevy_double_res::{DoubleBuffer, Side};
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
     ///
-    /// assert_eq!(current, &(20, 10));
-    /// assert_eq!(next, &mut (10, 20));
+    /// assert_eq!(tuple.side(), Side::A);
+    ///
+    /// tuple.swap();
+    ///
+    /// assert_eq!(tuple.side(), Side::B);
+    /// assert_eq!(tuple.current(), &tuple[u8::from(Side::B).into()]);
     /// ```
-    pub fn split_ordered(&mut self) -> (&T, &mut T) {
+    pub fn side(&self) -> Side {
+        self.debug_check_index();
         if self.index == 0 {
-            let (first, second) = self.split_mut();
-            (&*first, second)
+            Side::A
         } else {
-            let (first, second) = self.split_mut();
-            (&*second, first)
+            Side::B
         }
     }
 
-    /// Applies function to operate on current and next copies
+    /// Set current copy index from [Side]
     ///
-    /// More idiomatic version of [`DoubleBuffer::split_ordered`]
+    /// Unlike [`DoubleBuffer::set_index`], index can't be out of range
     ///
     /// # Example
     ///
     /// ```
-    /// use bevy_double_res::DoubleBuffer;
-    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (0, 0)], 0);
+    /// use bevy_double_res::{DoubleBuffer, Side};
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
     ///
-    /// tuple.apply(|current, next| {
-    ///     assert_eq!(current, &(10, 20));
-    ///     assert_eq!(next, &mut (0, 0));
+    /// tuple.set_side(Side::B);
     ///
-    ///     next.0 = current.1;
-    ///     next.1 = current.0;
-    /// });
+    /// assert_eq!(tuple.index(), 1);
+    /// assert_eq!(tuple.current(), &(20, 10));
+    /// ```
+    pub fn set_side(&mut self, side: Side) {
+        self.index = u8::from(side).into();
+    }
+
+    /// Get human-readable label of current copy, e.g. for debug overlays
+    ///
+    /// Labels are **"A"** and **"B"** like [Side], unless set with
+    /// [`DoubleBuffer::set_slot_labels`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    ///
+    /// assert_eq!(tuple.slot_label(), "A");
     ///
     /// tuple.swap();
     ///
-    /// let result = tuple.apply(|current, next| {
-    ///     assert_eq!(current, &(20, 10));
-    ///     assert_eq!(next, &mut (10, 20));
+    /// assert_eq!(tuple.slot_label(), "B");
+    /// ```
+    pub fn slot_label(&self) -> &'static str {
+        match (self.labels, self.side()) {
+            (Some(labels), _) => labels[self.index],
+            (None, Side::A) => "A",
+            (None, Side::B) => "B",
+        }
+    }
+
+    /// Set custom labels of copies, used by [`DoubleBuffer::slot_label`] and [Debug](fmt::Debug)
     ///
-    ///     return "You can return values from here too";
-    /// });
+    /// # Example
     ///
-    /// assert_eq!(result, "You can return values from here too");
     /// ```
-    pub fn apply<Res>(&mut self, f: impl FnOnce(&T, &mut T) -> Res) -> Res {
-        let (prev, next) = self.split_ordered();
-        f(prev, next)
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([10, 20], 1);
+    ///
+    /// tuple.set_slot_labels("front", "back");
+    ///
+    /// assert_eq!(tuple.slot_label(), "back");
+    /// assert_eq!(
+    ///     format!("{:?}", tuple),
+    ///     "DoubleBuffer { current: 20, next: 10, index: 1, slot: \"back\" }"
+    /// );
+    /// ```
+    pub fn set_slot_labels(&mut self, a: &'static str, b: &'static str) {
+        self.labels = Some([a, b]);
     }
-}
 
-impl<T> DoubleBuffer<T>
-where
-    T: Clone,
-{
-    /// Create double buffer of **T** from one copy of **T**
+    /// Set underlying current copy index to **0**, copies stay in place
     ///
-    /// Second copy will be cloned and index will be **0**
+    /// Always in range, unlike [`DoubleBuffer::set_index`]
     ///
     /// # Example
     ///
     /// ```
     /// use bevy_double_res::DoubleBuffer;
-    /// let tuple = DoubleBuffer::new((10, 20));
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    ///
+    /// tuple.swap();
+    /// tuple.reset_index();
     ///
-    /// assert_eq!(tuple.current(), &(10, 20));
-    /// assert_eq!(tuple.next(), &(10, 20));
     /// assert_eq!(tuple.index(), 0);
+    /// assert_eq!(tuple.buffer(), &[(10, 20), (20, 10)]);
     /// ```
-    pub fn new(value: T) -> Self {
-        Self::from_buffer([value.clone(), value], 0)
+    pub fn reset_index(&mut self) {
+        self.index = 0;
     }
-}
 
-impl<T> From<T> for DoubleBuffer<T>
-where
-    T: Clone,
-{
-    /// Create double buffer of **T** from one copy of **T** by conversion
+    /// Check whether current index differs from **baseline** captured earlier
     ///
-    /// Second copy will be cloned and index will be **0**
+    /// Lets external caches of copy references decide whether they have to be refreshed
     ///
     /// # Example
     ///
     /// ```
     /// use bevy_double_res::DoubleBuffer;
-    /// let tuple: DoubleBuffer<(u8, u8)> = (10, 20).into();
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    /// let baseline = tuple.index();
     ///
-    /// assert_eq!(tuple.current(), &(10, 20));
-    /// assert_eq!(tuple.next(), &(10, 20));
-    /// assert_eq!(tuple.index(), 0);
+    /// assert!(!tuple.is_swapped_from(baseline));
+    ///
+    /// tuple.swap();
+    ///
+    /// assert!(tuple.is_swapped_from(baseline));
     /// ```
-    fn from(value: T) -> Self {
-        Self::new(value)
+    pub fn is_swapped_from(&self, baseline: u8) -> bool {
+        self.index != usize::from(baseline)
     }
-}
 
-impl<T> Default for DoubleBuffer<T>
-where
-    T: Default + Clone,
-{
-    /// Create double buffer of **T** with default values of **T**
+    /// Access opposite of current copy index
     ///
-    /// Index will be **0**
+    /// [`DoubleBuffer::next`] reads copy under this index
     ///
     /// # Example
     ///
     /// ```
     /// use bevy_double_res::DoubleBuffer;
-    /// let tuple = DoubleBuffer::<(u8, u8)>::default();
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
     ///
-    /// assert_eq!(tuple.current(), &(0, 0));
-    /// assert_eq!(tuple.next(), &(0, 0));
-    /// assert_eq!(tuple.index(), 0);
+    /// assert_eq!(tuple.next_index(), 1);
+    ///
+    /// tuple.swap();
+    ///
+    /// assert_eq!(tuple.index(), 1);
+    /// assert_eq!(tuple.next_index(), 0);
     /// ```
-    fn default() -> Self {
-        Self::new(T::default())
+    pub fn next_index(&self) -> u8 {
+        self.debug_check_index();
+        1 - self.index as u8
     }
-}
+
+    /// Get readonly copy reference under opposite of current index
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    ///
+    /// assert_eq!(tuple.next(), &(20, 10));
+    ///
+    /// tuple.swap();
+    ///
+    /// assert_eq!(tuple.current(), &(20, 10));
+    /// assert_eq!(tuple.next(), &(10, 20));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// In debug builds panics with "DoubleBuffer index must be 0 or 1, got 2" if index was set
+    /// outside of range \[0, 1] with [`DoubleBuffer::set_index`], same for
+    /// [`DoubleBuffer::current`], [`DoubleBuffer::current_mut`] and [`DoubleBuffer::next_mut`]
+    ///
+    /// ```should_panic
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    ///
+    /// tuple.set_index(2);
+    /// tuple.next();
+    /// ```
+    pub fn next(&self) -> &T {
+        &self.buffer[usize::from(self.next_index())]
+    }
+
+    /// Get readonly copy reference under current index, or error if index is out of range
+    ///
+    /// Unlike [`DoubleBuffer::current`], never panics, even if index was set outside of range
+    /// \[0, 1] with [`DoubleBuffer::set_index`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::{DoubleBuffer, IndexOutOfRange};
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 1);
+    ///
+    /// assert_eq!(tuple.try_current(), Ok(&(20, 10)));
+    ///
+    /// tuple.set_index(2);
+    ///
+    /// assert_eq!(tuple.try_current(), Err(IndexOutOfRange(2)));
+    /// ```
+    pub fn try_current(&self) -> Result<&T, IndexOutOfRange> {
+        self.buffer
+            .get(self.index)
+            .ok_or(IndexOutOfRange(self.index))
+    }
+
+    /// Get readonly copy reference under opposite of current index, or error if index is out of
+    /// range
+    ///
+    /// Unlike [`DoubleBuffer::next`], never panics, even if index was set outside of range
+    /// \[0, 1] with [`DoubleBuffer::set_index`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::{DoubleBuffer, IndexOutOfRange};
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 1);
+    ///
+    /// assert_eq!(tuple.try_next(), Ok(&(10, 20)));
+    ///
+    /// tuple.set_index(2);
+    ///
+    /// assert_eq!(tuple.try_next(), Err(IndexOutOfRange(2)));
+    /// ```
+    pub fn try_next(&self) -> Result<&T, IndexOutOfRange> {
+        match self.index {
+            0 | 1 => Ok(&self.buffer[1 - self.index]),
+            index => Err(IndexOutOfRange(index)),
+        }
+    }
+
+    /// Get mutable copy reference under opposite of current index
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    ///
+    /// tuple.next_mut().0 = 999;
+    /// tuple.swap();
+    ///
+    /// assert_eq!(tuple.current(), &(999, 10));
+    /// assert_eq!(tuple.next(), &(10, 20));
+    /// ```
+    pub fn next_mut(&mut self) -> &mut T {
+        self.dirty = true;
+        &mut self.buffer[usize::from(self.next_index())]
+    }
+
+    /// Replace copy under opposite of current index, returning the old one
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([vec![1, 2], vec![3, 4]], 0);
+    ///
+    /// let mut old = tuple.replace_next(vec![5, 6]);
+    ///
+    /// assert_eq!(old, vec![3, 4]);
+    /// assert_eq!(tuple.next(), &vec![5, 6]);
+    ///
+    /// // Reuse allocation of the old copy
+    /// old.clear();
+    /// old.push(7);
+    /// tuple.swap();
+    /// tuple.replace_next(old);
+    ///
+    /// assert_eq!(tuple.next(), &vec![7]);
+    /// ```
+    pub fn replace_next(&mut self, value: T) -> T {
+        core::mem::replace(self.next_mut(), value)
+    }
+
+    /// Toggles current index between **0** and **1**
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    ///
+    /// assert_eq!(tuple.current(), &(10, 20));
+    /// assert_eq!(tuple.index(), 0);
+    ///
+    /// tuple.swap();
+    ///
+    /// assert_eq!(tuple.current(), &(20, 10));
+    /// assert_eq!(tuple.index(), 1);
+    ///
+    /// tuple.swap();
+    ///
+    /// assert_eq!(tuple.current(), &(10, 20));
+    /// assert_eq!(tuple.index(), 0);
+    /// ```
+    pub fn swap(&mut self) {
+        self.advance();
+    }
+
+    /// Exchange contents of both copies, index stays unchanged
+    ///
+    /// After it [`DoubleBuffer::current`] returns the same copy as after [`DoubleBuffer::swap`],
+    /// but index is left in opposite state, and copies are physically moved
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut swapped = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    /// let mut exchanged = swapped.clone();
+    ///
+    /// swapped.swap();
+    /// exchanged.swap_contents();
+    ///
+    /// assert_eq!(swapped.current(), exchanged.current());
+    /// assert_eq!(swapped.index(), 1);
+    /// assert_eq!(exchanged.index(), 0);
+    /// assert_eq!(exchanged.buffer(), &[(20, 10), (10, 20)]);
+    /// ```
+    pub fn swap_contents(&mut self) {
+        self.buffer_mut().swap(0, 1);
+    }
+
+    /// Commit next copy using custom [SwapStrategy]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::{CopyForward, DoubleBuffer, FlipIndex};
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    ///
+    /// tuple.advance_with(&FlipIndex);
+    ///
+    /// assert_eq!(tuple.current(), &(20, 10));
+    ///
+    /// tuple.advance_with(&CopyForward);
+    ///
+    /// assert_eq!(tuple.current(), &(10, 20));
+    /// assert_eq!(tuple.next(), &(10, 20));
+    /// ```
+    pub fn advance_with<S>(&mut self, strategy: &S)
+    where
+        S: SwapStrategy<T>,
+    {
+        strategy.apply(self);
+    }
+
+    /// Returns guard swapping buffer when it goes out of scope
+    ///
+    /// Swap happens even on early return, so update code can't forget to commit
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (0, 0)], 0);
+    ///
+    /// {
+    ///     let mut guard = tuple.swap_on_drop();
+    ///     guard.next_mut().0 = 999;
+    ///
+    ///     assert_eq!(guard.index(), 0);
+    /// }
+    ///
+    /// assert_eq!(tuple.index(), 1);
+    /// assert_eq!(tuple.current(), &(999, 0));
+    /// ```
+    pub fn swap_on_drop(&mut self) -> SwapGuard<'_, T> {
+        SwapGuard { buffer: self }
+    }
+
+    /// Marks swap as pending and returns token performing it once resolved with
+    /// [`PendingSwap::resolve`]
+    ///
+    /// Token does not borrow the buffer, so swap can be deferred past other borrows. Dropping
+    /// token without resolving it cancels the swap, pending mark is cleared by the next swap of
+    /// the buffer
+    ///
+    /// Token remembers only [generation](crate::MultiBuffer::generation) of the buffer, not the
+    /// buffer itself. Buffer without pending swap rejects it, but another buffer with swap
+    /// deferred at the same generation accepts it, so resolve token against the buffer it was
+    /// created from
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    ///
+    /// let pending = tuple.defer_swap();
+    /// let current = tuple.current();
+    ///
+    /// assert_eq!(current, &(10, 20));
+    /// assert!(pending.resolve(&mut tuple));
+    /// assert_eq!(tuple.index(), 1);
+    ///
+    /// let cancelled = tuple.defer_swap();
+    /// drop(cancelled);
+    ///
+    /// assert_eq!(tuple.index(), 1);
+    /// ```
+    ///
+    /// Token is not bound to the buffer it was created from:
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut other = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    /// let mut another = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    /// let pending = other.defer_swap();
+    ///
+    /// // No swap is pending on another buffer, token is rejected
+    /// assert!(!pending.resolve(&mut another));
+    /// assert_eq!(another.index(), 0);
+    ///
+    /// let pending = other.defer_swap();
+    /// let _unused = another.defer_swap();
+    ///
+    /// // Both buffers deferred swap at generation 0, token of one swaps the other
+    /// assert!(pending.resolve(&mut another));
+    /// assert_eq!(another.index(), 1);
+    /// assert_eq!(other.index(), 0);
+    /// ```
+    pub fn defer_swap(&mut self) -> PendingSwap {
+        self.pending_swap = true;
+        PendingSwap {
+            generation: self.generation,
+        }
+    }
+
+    /// Consumes buffer, returning it with toggled current index
+    ///
+    /// Copies are **not** touched
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0).swapped();
+    ///
+    /// assert_eq!(tuple.current(), &(20, 10));
+    /// assert_eq!(tuple.next(), &(10, 20));
+    /// assert_eq!(tuple.buffer(), &[(10, 20), (20, 10)]);
+    /// ```
+    pub fn swapped(mut self) -> Self {
+        self.swap();
+        self
+    }
+
+    /// Swaps only if **condition** is true, returning whether swap happened
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    ///
+    /// assert!(!tuple.swap_if(false));
+    /// assert_eq!(tuple.index(), 0);
+    ///
+    /// assert!(tuple.swap_if(true));
+    /// assert_eq!(tuple.index(), 1);
+    /// ```
+    pub fn swap_if(&mut self, condition: bool) -> bool {
+        if condition {
+            self.swap();
+        }
+        condition
+    }
+
+    /// Returns readonly references to current and next copies, in that order
+    ///
+    /// Readonly counterpart of [`DoubleBuffer::split_ordered`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    ///
+    /// assert_eq!(tuple.current_pair(), (&(10, 20), &(20, 10)));
+    ///
+    /// tuple.swap();
+    ///
+    /// assert_eq!(tuple.current_pair(), (&(20, 10), &(10, 20)));
+    /// ```
+    pub fn current_pair(&self) -> (&T, &T) {
+        (self.current(), self.next())
+    }
+
+    /// Iterate over readonly references to copies, current copy first
+    ///
+    /// Unlike [`MultiBuffer::iter`], which follows physical order of copies, order **does**
+    /// depend on current index
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([10, 20], 0);
+    ///
+    /// tuple.swap();
+    ///
+    /// assert_eq!(tuple.iter().collect::<Vec<_>>(), vec![&10, &20]);
+    /// assert_eq!(tuple.iter_ordered().collect::<Vec<_>>(), vec![&20, &10]);
+    /// ```
+    pub fn iter_ordered(&self) -> impl Iterator<Item = &T> {
+        [self.current(), self.next()].into_iter()
+    }
+
+    /// Calls function with current and next copies, returning computed difference
+    ///
+    /// Readonly counterpart of [`DoubleBuffer::apply`], e.g. to find out how far next state is
+    /// from current one
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (15, 5)], 0);
+    ///
+    /// let delta = tuple.diff(|current, next| (next.0 - current.0, next.1 - current.1));
+    ///
+    /// assert_eq!(delta, (5, -15));
+    ///
+    /// tuple.swap();
+    ///
+    /// assert_eq!(tuple.diff(|current, next| next.0 - current.0), -5);
+    /// ```
+    pub fn diff<D>(&self, f: impl FnOnce(&T, &T) -> D) -> D {
+        f(self.current(), self.next())
+    }
+
+    /// Compare current and next copies of two buffers, regardless of which slot is current
+    ///
+    /// Unlike [PartialEq], physical position of copies and index do not matter
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    /// let swapped = DoubleBuffer::from_buffer([(20, 10), (10, 20)], 1);
+    ///
+    /// assert_ne!(tuple, swapped);
+    /// assert!(tuple.logically_eq(&swapped));
+    /// assert!(!tuple.logically_eq(&swapped.clone().swapped()));
+    /// ```
+    pub fn logically_eq(&self, other: &Self) -> bool
+    where
+        T: PartialEq,
+    {
+        self.current_pair() == other.current_pair()
+    }
+
+    /// Returns two readonly references to copies
+    ///
+    /// Order does **not** depend on current index!
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    ///
+    /// assert_eq!(tuple.split(), (&(10, 20), &(20, 10)));
+    ///
+    /// tuple.swap();
+    ///
+    /// // Same as before!
+    /// assert_eq!(tuple.split(), (&(10, 20), &(20, 10)));
+    /// ```
+    pub fn split(&self) -> (&T, &T) {
+        let (first, second) = self.buffer.split_at(1);
+        (&first[0], &second[0])
+    }
+
+    /// Returns two readonly references to copies and current index
+    ///
+    /// Same as [`DoubleBuffer::split`], order does **not** depend on current index
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    /// tuple.swap();
+    ///
+    /// let (first, second, index) = tuple.split_with_index();
+    ///
+    /// assert_eq!(index, tuple.index());
+    /// assert_eq!([*first, *second], *tuple.buffer());
+    /// ```
+    pub fn split_with_index(&self) -> (&T, &T, u8) {
+        let (first, second) = self.split();
+        (first, second, self.index())
+    }
+
+    /// Returns two mutable references to copies
+    ///
+    /// Order does **not** depend on current index!
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    ///
+    /// assert_eq!(tuple.split_mut(), (&mut (10, 20), &mut (20, 10)));
+    ///
+    /// tuple.swap();
+    ///
+    /// // Same as before!
+    /// assert_eq!(tuple.split_mut(), (&mut (10, 20), &mut (20, 10)));
+    /// ```
+    pub fn split_mut(&mut self) -> (&mut T, &mut T) {
+        self.dirty = true;
+        let (first, second) = self.buffer.split_at_mut(1);
+        (&mut first[0], &mut second[0])
+    }
+
+    /// Returns array of readonly references to copies
+    ///
+    /// Same as [`DoubleBuffer::split`], order does **not** depend on current index, but array is
+    /// easier to pass to array-generic code
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let tuple = DoubleBuffer::from_buffer([vec![1, 2, 3], vec![4]], 1);
+    ///
+    /// assert_eq!(tuple.each_ref().map(Vec::len), [3, 1]);
+    /// ```
+    pub fn each_ref(&self) -> [&T; 2] {
+        let [first, second] = &self.buffer;
+        [first, second]
+    }
+
+    /// Returns array of mutable references to copies
+    ///
+    /// Same as [`DoubleBuffer::split_mut`], order does **not** depend on current index
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([vec![1, 2, 3], vec![4]], 1);
+    ///
+    /// for copy in tuple.each_mut() {
+    ///     copy.push(0);
+    /// }
+    ///
+    /// assert_eq!(tuple.buffer(), &[vec![1, 2, 3, 0], vec![4, 0]]);
+    /// ```
+    pub fn each_mut(&mut self) -> [&mut T; 2] {
+        self.dirty = true;
+        let [first, second] = &mut self.buffer;
+        [first, second]
+    }
+
+    /// Returns two references to copies
+    ///
+    /// Order **does** depend on current index and is:
+    /// 1. **current** - immutable reference
+    /// 2. **next** - mutable reference
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (0, 0)], 0);
+    ///
+    /// let (current, next) = tuple.split_ordered();
+    ///
+    /// assert_eq!(current, &(10, 20));
+    /// assert_eq!(next, &mut (0, 0));
+    ///
+    /// next.0 = current.1;
+    /// next.1 = current.0;
+    ///
+    /// tuple.swap();
+    ///
+    /// let (current, next) = tuple.split_ordered();
+    ///
+    /// assert_eq!(current, &(20, 10));
+    /// assert_eq!(next, &mut (10, 20));
+    /// ```
+    pub fn split_ordered(&mut self) -> (&T, &mut T) {
+        let (current, next) = self.split_ordered_mut();
+        (&*current, next)
+    }
+
+    /// Returns references to current and next copies as named fields
+    ///
+    /// Same as [`DoubleBuffer::split_ordered`], but copies can't be mixed up
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (0, 0)], 0);
+    ///
+    /// let view = tuple.view_mut();
+    ///
+    /// view.next.0 = view.current.1;
+    /// view.next.1 = view.current.0;
+    ///
+    /// assert_eq!(tuple.next(), &(20, 10));
+    /// ```
+    pub fn view_mut(&mut self) -> CurrentNext<'_, T> {
+        let (current, next) = self.split_ordered();
+        CurrentNext { current, next }
+    }
+
+    /// Split buffer into reader of current copy and writer of next copy
+    ///
+    /// Both halves borrow the buffer at once, so they can be handed to producer and consumer
+    /// separately
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (0, 0)], 0);
+    ///
+    /// let (reader, mut writer) = tuple.split_rw();
+    ///
+    /// *writer.next_mut() = (999, 999);
+    ///
+    /// assert_eq!(reader.current(), &(10, 20));
+    ///
+    /// tuple.swap();
+    ///
+    /// assert_eq!(tuple.current(), &(999, 999));
+    /// ```
+    pub fn split_rw(&mut self) -> (Reader<'_, T>, Writer<'_, T>) {
+        let (current, next) = self.split_ordered();
+        (Reader { current }, Writer { next })
+    }
+
+    /// Returns view reading current copy and writing next one, swapping buffer once committed
+    ///
+    /// Unlike [`DoubleBuffer::swap_on_drop`], swap happens only on explicit
+    /// [`Pipeline::commit`], dropping view leaves buffer unswapped
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (0, 0)], 0);
+    ///
+    /// let mut pipeline = tuple.pipeline();
+    /// let (a, b) = *pipeline.current();
+    /// *pipeline.next_mut() = (b, a);
+    /// pipeline.commit();
+    ///
+    /// assert_eq!(tuple.current(), &(20, 10));
+    /// assert_eq!(tuple.index(), 1);
+    /// ```
+    pub fn pipeline(&mut self) -> Pipeline<'_, T> {
+        Pipeline { buffer: self }
+    }
+
+    /// Returns two mutable references to copies
+    ///
+    /// Order **does** depend on current index and is:
+    /// 1. **current** - mutable reference
+    /// 2. **next** - mutable reference
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (0, 0)], 0);
+    ///
+    /// let (current, next) = tuple.split_ordered_mut();
+    ///
+    /// next.0 = current.1;
+    /// next.1 = current.0;
+    /// current.0 = 0;
+    ///
+    /// tuple.swap();
+    ///
+    /// let (current, next) = tuple.split_ordered_mut();
+    ///
+    /// assert_eq!(current, &mut (20, 10));
+    /// assert_eq!(next, &mut (0, 20));
+    /// ```
+    pub fn split_ordered_mut(&mut self) -> (&mut T, &mut T) {
+        if self.index == 0 {
+            let (first, second) = self.split_mut();
+            (first, second)
+        } else {
+            let (first, second) = self.split_mut();
+            (second, first)
+        }
+    }
+
+    /// Applies function to operate on current and next copies
+    ///
+    /// More idiomatic version of [`DoubleBuffer::split_ordered`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (0, 0)], 0);
+    ///
+    /// tuple.apply(|current, next| {
+    ///     assert_eq!(current, &(10, 20));
+    ///     assert_eq!(next, &mut (0, 0));
+    ///
+    ///     next.0 = current.1;
+    ///     next.1 = current.0;
+    /// });
+    ///
+    /// tuple.swap();
+    ///
+    /// let result = tuple.apply(|current, next| {
+    ///     assert_eq!(current, &(20, 10));
+    ///     assert_eq!(next, &mut (10, 20));
+    ///
+    ///     return "You can return values from here too";
+    /// });
+    ///
+    /// assert_eq!(result, "You can return values from here too");
+    /// ```
+    pub fn apply<Res>(&mut self, f: impl FnOnce(&T, &mut T) -> Res) -> Res {
+        let (prev, next) = self.split_ordered();
+        f(prev, next)
+    }
+
+    /// Applies function to operate on current and next copies, then swaps them
+    ///
+    /// Shorthand for [`DoubleBuffer::apply`] followed by [`DoubleBuffer::swap`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::new((10, 20));
+    ///
+    /// tuple.apply_swap(|current, next| {
+    ///     next.0 = current.1;
+    ///     next.1 = current.0;
+    /// });
+    ///
+    /// assert_eq!(tuple.current(), &(20, 10));
+    ///
+    /// let result = tuple.apply_swap(|current, next| {
+    ///     next.0 = current.1;
+    ///     next.1 = current.0;
+    ///
+    ///     return "You can return values from here too";
+    /// });
+    ///
+    /// assert_eq!(tuple.current(), &(10, 20));
+    /// assert_eq!(result, "You can return values from here too");
+    /// ```
+    pub fn apply_swap<Res>(&mut self, f: impl FnOnce(&T, &mut T) -> Res) -> Res {
+        let result = self.apply(f);
+        self.swap();
+        result
+    }
+
+    /// Applies function to operate on current and next copies, then swaps them if it returned
+    /// **true**
+    ///
+    /// Returns the same value, like [`DoubleBuffer::swap_if`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::new((10, 20));
+    ///
+    /// let swapped = tuple.apply_maybe_swap(|current, next| {
+    ///     next.0 = current.0.min(5);
+    ///     next != current
+    /// });
+    ///
+    /// assert!(swapped);
+    /// assert_eq!(tuple.current(), &(5, 20));
+    ///
+    /// let swapped = tuple.apply_maybe_swap(|current, next| {
+    ///     next.0 = current.0.min(5);
+    ///     next != current
+    /// });
+    ///
+    /// assert!(!swapped);
+    /// assert_eq!(tuple.index(), 1);
+    /// ```
+    pub fn apply_maybe_swap(&mut self, f: impl FnOnce(&T, &mut T) -> bool) -> bool {
+        let condition = self.apply(f);
+        self.swap_if(condition)
+    }
+
+    /// Replace next copy with value computed from current one, then swap them
+    ///
+    /// Unlike [`DoubleBuffer::apply_swap`], **f** produces the whole new value and has no access
+    /// to the old next copy
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut counter = DoubleBuffer::new(0);
+    ///
+    /// counter.step(|current| current + 1);
+    /// counter.step(|current| current + 1);
+    ///
+    /// assert_eq!(counter.current(), &2);
+    /// assert_eq!(counter.next(), &1);
+    /// ```
+    pub fn step(&mut self, f: impl FnOnce(&T) -> T) {
+        let next = f(self.current());
+        self.replace_next(next);
+        self.swap();
+    }
+
+    /// Hand next copy to **sink**, then swap, so flushed copy becomes current
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut samples = DoubleBuffer::new_with(vec![0.0], vec![0.5, 1.0]);
+    /// let mut output = Vec::new();
+    ///
+    /// let flushed = samples.flush_next(|next| {
+    ///     output.extend_from_slice(next);
+    ///     next.len()
+    /// });
+    ///
+    /// assert_eq!(flushed, 2);
+    /// assert_eq!(output, [0.5, 1.0]);
+    /// assert_eq!(samples.current(), &[0.5, 1.0]);
+    /// ```
+    pub fn flush_next<Res>(&mut self, sink: impl FnOnce(&T) -> Res) -> Res {
+        let result = sink(self.next());
+        self.swap();
+        result
+    }
+
+    /// Applies function to operate on next copy only
+    ///
+    /// Narrower version of [`DoubleBuffer::apply`] for updates not reading current copy
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::new((10, 20));
+    ///
+    /// let result = tuple.update_next(|next| {
+    ///     next.0 = 999;
+    ///
+    ///     return "You can return values from here too";
+    /// });
+    /// tuple.swap();
+    ///
+    /// assert_eq!(tuple.current(), &(999, 20));
+    /// assert_eq!(result, "You can return values from here too");
+    /// ```
+    pub fn update_next<Res>(&mut self, f: impl FnOnce(&mut T) -> Res) -> Res {
+        f(self.next_mut())
+    }
+
+    /// Applies function to operate on current and next copies only if **predicate** holds for
+    /// current copy
+    ///
+    /// Returns **None** without touching next copy if predicate fails
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::new((10, 20));
+    ///
+    /// let skipped = tuple.try_apply(
+    ///     |current| current.0 > 10,
+    ///     |current, next| next.0 = current.0 + 1,
+    /// );
+    ///
+    /// assert_eq!(skipped, None);
+    /// assert_eq!(tuple.next(), &(10, 20));
+    ///
+    /// let executed = tuple.try_apply(
+    ///     |current| current.0 == 10,
+    ///     |current, next| {
+    ///         next.0 = current.0 + 1;
+    ///         next.0
+    ///     },
+    /// );
+    ///
+    /// assert_eq!(executed, Some(11));
+    /// assert_eq!(tuple.next(), &(11, 20));
+    /// ```
+    pub fn try_apply<Res>(
+        &mut self,
+        predicate: impl FnOnce(&T) -> bool,
+        f: impl FnOnce(&T, &mut T) -> Res,
+    ) -> Option<Res> {
+        if predicate(self.current()) {
+            Some(self.apply(f))
+        } else {
+            None
+        }
+    }
+
+    /// Reduce both copies into single value, visiting them in raw order
+    ///
+    /// Copy under index **0** is visited first regardless of current index
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let lists = DoubleBuffer::new_with(vec![1, 2, 3], vec![4, 5]);
+    ///
+    /// assert_eq!(lists.fold_both(0, |total, list| total + list.len()), 5);
+    /// ```
+    pub fn fold_both<A>(&self, init: A, f: impl FnMut(A, &T) -> A) -> A {
+        self.buffer.iter().fold(init, f)
+    }
+
+    /// Run the same mutation on both copies, visiting them in raw order
+    ///
+    /// Unlike [`DoubleBuffer::apply`], current and next copies are not distinguished
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut timers = DoubleBuffer::new_with((0, "first"), (5, "second"));
+    ///
+    /// timers.for_each_mut(|(ticks, _)| *ticks += 1);
+    ///
+    /// assert_eq!(timers.current(), &(1, "first"));
+    /// assert_eq!(timers.next(), &(6, "second"));
+    /// ```
+    pub fn for_each_mut(&mut self, f: impl FnMut(&mut T)) {
+        self.iter_mut().for_each(f);
+    }
+
+    /// Combine two buffers into one buffer of pairs, slot by slot
+    ///
+    /// Index and generation of the result are taken from **self**, those of **other** are
+    /// ignored. Aligning indices beforehand is up to the caller
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let positions = DoubleBuffer::from_buffer([10, 20], 1);
+    /// let velocities = DoubleBuffer::from_buffer([1, 2], 1);
+    ///
+    /// let bodies = positions.zip(velocities);
+    ///
+    /// assert_eq!(bodies.current(), &(20, 2));
+    /// assert_eq!(bodies.next(), &(10, 1));
+    /// assert_eq!(bodies.index(), 1);
+    /// ```
+    pub fn zip<U>(self, other: DoubleBuffer<U>) -> DoubleBuffer<(T, U)> {
+        let meta = self.with_meta_from([(); 2]);
+        let [first, second] = self.buffer;
+        let [other_first, other_second] = other.buffer;
+        meta.with_meta_from([(first, other_first), (second, other_second)])
+    }
+}
+
+impl<A, B> DoubleBuffer<(A, B)> {
+    /// Split buffer of pairs into two buffers, slot by slot
+    ///
+    /// Reverse of [`DoubleBuffer::zip`], both buffers get the same index and generation
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let bodies = DoubleBuffer::from_buffer([(10, 1), (20, 2)], 1);
+    ///
+    /// let (positions, velocities) = bodies.unzip();
+    ///
+    /// assert_eq!(positions.into_inner(), ([10, 20], 1));
+    /// assert_eq!(velocities.into_inner(), ([1, 2], 1));
+    /// ```
+    pub fn unzip(self) -> (DoubleBuffer<A>, DoubleBuffer<B>) {
+        let meta = self.with_meta_from([(); 2]);
+        let [(first_a, first_b), (second_a, second_b)] = self.buffer;
+        (
+            meta.with_meta_from([first_a, second_a]),
+            meta.with_meta_from([first_b, second_b]),
+        )
+    }
+}
+
+impl<T> DoubleBuffer<Option<T>> {
+    /// Take value out of current copy, leaving **None** in its place
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut message = DoubleBuffer::new_with(Some("payload"), None);
+    ///
+    /// assert!(message.is_current_some());
+    /// assert_eq!(message.take_current_opt(), Some("payload"));
+    /// assert_eq!(message.current(), &None);
+    /// assert!(!message.is_current_some());
+    /// ```
+    pub fn take_current_opt(&mut self) -> Option<T> {
+        self.current_mut().take()
+    }
+
+    /// Check if current copy holds a value
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut message = DoubleBuffer::new_with(None, Some("payload"));
+    ///
+    /// assert!(!message.is_current_some());
+    ///
+    /// message.swap();
+    ///
+    /// assert!(message.is_current_some());
+    /// ```
+    pub fn is_current_some(&self) -> bool {
+        self.current().is_some()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> DoubleBuffer<Vec<T>> {
+    /// Drain every item of current copy, keeping its allocation for reuse
+    ///
+    /// Together with [`DoubleBuffer::push_next`] forms classic command buffer: commands are
+    /// pushed into next copy, buffer is swapped and current copy is drained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut commands = DoubleBuffer::<Vec<&str>>::default();
+    ///
+    /// commands.push_next("spawn");
+    /// commands.push_next("despawn");
+    /// commands.swap();
+    /// commands.push_next("insert");
+    ///
+    /// assert_eq!(commands.drain_current().collect::<Vec<_>>(), ["spawn", "despawn"]);
+    /// assert!(commands.current().is_empty());
+    /// assert_eq!(commands.next(), &["insert"]);
+    /// ```
+    pub fn drain_current(&mut self) -> std::vec::Drain<'_, T> {
+        self.current_mut().drain(..)
+    }
+
+    /// Push item into next copy
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut commands = DoubleBuffer::<Vec<&str>>::default();
+    ///
+    /// commands.push_next("spawn");
+    ///
+    /// assert!(commands.current().is_empty());
+    /// assert_eq!(commands.next(), &["spawn"]);
+    /// ```
+    pub fn push_next(&mut self, item: T) {
+        self.next_mut().push(item);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> DoubleBuffer<std::sync::Arc<T>> {
+    /// Get mutable reference to value of next copy, cloning it first if allocation is shared
+    ///
+    /// Lets both copies start from the same shared value and pay for clone only when next copy is
+    /// actually written, leaving current copy untouched
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// use std::sync::Arc;
+    /// let mut buffer = DoubleBuffer::new(Arc::new(vec![1, 2, 3]));
+    ///
+    /// assert!(Arc::ptr_eq(buffer.current(), buffer.next()));
+    ///
+    /// buffer.make_next_unique().push(4);
+    ///
+    /// assert!(!Arc::ptr_eq(buffer.current(), buffer.next()));
+    /// assert_eq!(**buffer.current(), [1, 2, 3]);
+    /// assert_eq!(**buffer.next(), [1, 2, 3, 4]);
+    /// ```
+    pub fn make_next_unique(&mut self) -> &mut T
+    where
+        T: Clone,
+    {
+        std::sync::Arc::make_mut(self.next_mut())
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl<T> DoubleBuffer<T>
+where
+    T: bytemuck::Zeroable,
+{
+    /// Create double buffer of **T** with both copies zeroed
+    ///
+    /// Index will be **0**. Unlike [`DoubleBuffer::default`], nothing is cloned, which is cheaper
+    /// for large plain data, e.g. compute scratch buffers
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "bytemuck")]
+    /// # {
+    /// use bevy_double_res::DoubleBuffer;
+    ///
+    /// #[derive(Clone, Copy)]
+    /// struct Scratch([f32; 4096]);
+    ///
+    /// unsafe impl bytemuck::Zeroable for Scratch {}
+    ///
+    /// let scratch = DoubleBuffer::<Scratch>::zeroed();
+    ///
+    /// assert!(scratch.iter().all(|copy| copy.0.iter().all(|value| *value == 0.0)));
+    /// assert_eq!(scratch.index(), 0);
+    /// # }
+    /// ```
+    pub fn zeroed() -> Self {
+        Self::from_fn(|_| T::zeroed())
+    }
+}
+
+impl<T> DoubleBuffer<T>
+where
+    T: Clone,
+{
+    /// Create double buffer of **T** from one copy of **T**
+    ///
+    /// Second copy will be cloned and index will be **0**
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let tuple = DoubleBuffer::new((10, 20));
+    ///
+    /// assert_eq!(tuple.current(), &(10, 20));
+    /// assert_eq!(tuple.next(), &(10, 20));
+    /// assert_eq!(tuple.index(), 0);
+    /// ```
+    pub fn new(value: T) -> Self {
+        Self::from_buffer([value.clone(), value], 0)
+    }
+
+    /// Clone current copy into next one, index stays unchanged
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 1);
+    ///
+    /// tuple.current_mut().0 = 999;
+    /// tuple.sync();
+    ///
+    /// assert_eq!(tuple.current(), &(999, 10));
+    /// assert_eq!(tuple.next(), &(999, 10));
+    /// assert_eq!(tuple.index(), 1);
+    /// ```
+    pub fn sync(&mut self) {
+        self.apply(|current, next| next.clone_from(current));
+    }
+
+    /// Clone next copy into current one, index stays unchanged
+    ///
+    /// Unlike [`DoubleBuffer::swap`], changes made to next copy are visible through
+    /// [`DoubleBuffer::current`] without changing which copy is current. This is reverse of
+    /// [`DoubleBuffer::sync`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    ///
+    /// let mut swapped = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    /// swapped.swap();
+    ///
+    /// let mut synced = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    /// synced.sync();
+    ///
+    /// tuple.commit();
+    ///
+    /// assert_eq!(tuple.current(), swapped.current());
+    /// assert_eq!(tuple.current(), &(20, 10));
+    /// assert_eq!(tuple.next(), &(20, 10));
+    /// assert_eq!(tuple.index(), 0);
+    ///
+    /// assert_eq!(synced.current(), &(10, 20));
+    /// assert_eq!(synced.next(), &(10, 20));
+    /// ```
+    pub fn commit(&mut self) {
+        let (current, next) = self.split_ordered_mut();
+        current.clone_from(next);
+    }
+
+    /// Overwrite both copies with **value**, index stays unchanged
+    ///
+    /// First copy will be cloned and second one will be moved
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 1);
+    ///
+    /// tuple.fill((0, 0));
+    ///
+    /// assert_eq!(tuple.current(), &(0, 0));
+    /// assert_eq!(tuple.next(), &(0, 0));
+    /// assert_eq!(tuple.index(), 1);
+    /// ```
+    pub fn fill(&mut self, value: T) {
+        *self.buffer_mut() = [value.clone(), value];
+    }
+
+    /// Applies fallible function to operate on current and next copies, restoring next copy if it
+    /// fails
+    ///
+    /// Next copy is cloned beforehand, so partial writes are never left behind on error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::new((10, 20));
+    ///
+    /// let failed: Result<(), &str> = tuple.apply_result(|current, next| {
+    ///     next.0 = current.0 * 100;
+    ///     Err("validation failed")
+    /// });
+    ///
+    /// assert_eq!(failed, Err("validation failed"));
+    /// assert_eq!(tuple.next(), &(10, 20));
+    ///
+    /// let succeeded: Result<i32, &str> = tuple.apply_result(|current, next| {
+    ///     next.0 = current.0 + 1;
+    ///     Ok(next.0)
+    /// });
+    ///
+    /// assert_eq!(succeeded, Ok(11));
+    /// assert_eq!(tuple.next(), &(11, 20));
+    /// ```
+    pub fn apply_result<Res, E>(
+        &mut self,
+        f: impl FnOnce(&T, &mut T) -> Result<Res, E>,
+    ) -> Result<Res, E> {
+        let snapshot = self.next().clone();
+        let result = self.apply(f);
+        if result.is_err() {
+            *self.next_mut() = snapshot;
+        }
+        result
+    }
+}
+
+impl<T> DoubleBuffer<T>
+where
+    T: Copy,
+{
+    /// Create double buffer of **T** from one copy of **T** by copying it
+    ///
+    /// Same as [`DoubleBuffer::new`], but makes it clear that no expensive clone happens.
+    /// Index will be **0**
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let tuple = DoubleBuffer::new_copy((10, 20));
+    ///
+    /// assert_eq!(tuple.current(), &(10, 20));
+    /// assert_eq!(tuple.next(), &(10, 20));
+    /// assert_eq!(tuple.index(), 0);
+    /// ```
+    pub fn new_copy(value: T) -> Self {
+        Self::from_buffer([value, value], 0)
+    }
+}
+
+impl<T> DoubleBuffer<T>
+where
+    T: Default,
+{
+    /// Create double buffer of **T** from one copy of **T** and **T::default()**
+    ///
+    /// **value** will be current and index will be **0**. Unlike [`DoubleBuffer::new`], large
+    /// **value** is not cloned
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let payload = DoubleBuffer::from_first(vec![1; 1024]);
+    ///
+    /// assert_eq!(payload.current(), &vec![1; 1024]);
+    /// assert_eq!(payload.next(), &vec![]);
+    /// assert_eq!(payload.index(), 0);
+    /// ```
+    pub fn from_first(value: T) -> Self {
+        Self::from_buffer([value, T::default()], 0)
+    }
+
+    /// Take copy under current index, leaving **T::default()** in its place
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut names = DoubleBuffer::new_with(String::from("first"), String::from("second"));
+    ///
+    /// assert_eq!(names.take_current(), "first");
+    /// assert_eq!(names.current(), "");
+    /// assert_eq!(names.next(), "second");
+    /// ```
+    pub fn take_current(&mut self) -> T {
+        core::mem::take(self.current_mut())
+    }
+
+    /// Take copy under opposite of current index, leaving **T::default()** in its place
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut names = DoubleBuffer::new_with(String::from("first"), String::from("second"));
+    ///
+    /// assert_eq!(names.take_next(), "second");
+    /// assert_eq!(names.next(), "");
+    /// assert_eq!(names.current(), "first");
+    /// ```
+    pub fn take_next(&mut self) -> T {
+        core::mem::take(self.next_mut())
+    }
+
+    /// Transform current copy in place, next copy stays untouched
+    ///
+    /// Current copy is moved into **f**, leaving **T::default()** in its place until **f**
+    /// returns. That's why **T** has to implement [Default]: without placeholder value current
+    /// copy could not be moved out safely, and if **f** panics it stays **T::default()**. Types
+    /// without [Default] can be modified in place with
+    /// [`DoubleBuffer::current_mut`](crate::MultiBuffer::current_mut)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut numbers = DoubleBuffer::new_with(7, 3);
+    ///
+    /// numbers.map_current(|value| value * value);
+    ///
+    /// assert_eq!(numbers.current(), &49);
+    /// assert_eq!(numbers.next(), &3);
+    /// ```
+    ///
+    /// Panic inside **f** leaves default value in current copy:
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// use std::panic::{catch_unwind, AssertUnwindSafe};
+    ///
+    /// let mut numbers = DoubleBuffer::new_with(7, 3);
+    /// let result = catch_unwind(AssertUnwindSafe(|| {
+    ///     numbers.map_current(|_| panic!("transform failed"));
+    /// }));
+    ///
+    /// assert!(result.is_err());
+    /// assert_eq!(numbers.current(), &0);
+    /// assert_eq!(numbers.next(), &3);
+    /// ```
+    pub fn map_current(&mut self, f: impl FnOnce(T) -> T) {
+        let value = self.take_current();
+        *self.current_mut() = f(value);
+    }
+
+    /// Reset both copies to **T::default()** and index to **0**
+    ///
+    /// Unlike [`DoubleBuffer::fill`], **T** is not required to be cloneable
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    ///
+    /// tuple.apply_swap(|current, next| next.0 = current.0 + 1);
+    /// tuple.clear();
+    ///
+    /// assert_eq!(tuple.current(), &(0, 0));
+    /// assert_eq!(tuple.next(), &(0, 0));
+    /// assert_eq!(tuple.index(), 0);
+    /// ```
+    pub fn clear(&mut self) {
+        *self.buffer_mut() = [T::default(), T::default()];
+        self.index = 0;
+    }
+}
+
+impl<T> From<T> for DoubleBuffer<T>
+where
+    T: Clone,
+{
+    /// Create double buffer of **T** from one copy of **T** by conversion
+    ///
+    /// Second copy will be cloned and index will be **0**
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let tuple: DoubleBuffer<(u8, u8)> = (10, 20).into();
+    ///
+    /// assert_eq!(tuple.current(), &(10, 20));
+    /// assert_eq!(tuple.next(), &(10, 20));
+    /// assert_eq!(tuple.index(), 0);
+    /// ```
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> From<[T; 2]> for DoubleBuffer<T> {
+    /// Create double buffer of **T** from two distinct copies of **T** by conversion
+    ///
+    /// Index will be **0**
+    ///
+    /// This does not conflict with conversion from single **T**, because **T** can never be the
+    /// same type as **\[T; 2]**. However, array is a cloneable value too, so element type has to
+    /// be known to pick this conversion over creating **DoubleBuffer<\[T; 2]>**
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let tuple = DoubleBuffer::<(u8, u8)>::from([(10, 20), (20, 10)]);
+    ///
+    /// assert_eq!(tuple.current(), &(10, 20));
+    /// assert_eq!(tuple.next(), &(20, 10));
+    /// assert_eq!(tuple.index(), 0);
+    ///
+    /// // Same array as a single copy
+    /// let arrays = DoubleBuffer::<[(u8, u8); 2]>::from([(10, 20), (20, 10)]);
+    ///
+    /// assert_eq!(arrays.current(), &[(10, 20), (20, 10)]);
+    /// ```
+    fn from(buffer: [T; 2]) -> Self {
+        Self::from_buffer(buffer, 0)
+    }
+}
+
+impl<T> From<(T, T)> for DoubleBuffer<T> {
+    /// Create double buffer of **T** from pair of distinct copies of **T** by conversion
+    ///
+    /// Index will be **0**
+    ///
+    /// Same as with arrays, element type has to be known to pick this conversion over creating
+    /// **DoubleBuffer<(T, T)>**
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let tuple: DoubleBuffer<(u8, u8)> = ((10, 20), (20, 10)).into();
+    ///
+    /// assert_eq!(tuple.current(), &(10, 20));
+    /// assert_eq!(tuple.next(), &(20, 10));
+    /// assert_eq!(tuple.index(), 0);
+    /// ```
+    fn from((first, second): (T, T)) -> Self {
+        Self::from_buffer([first, second], 0)
+    }
+}
+
+impl<T> FromIterator<T> for DoubleBuffer<T> {
+    /// Create double buffer of **T** from first two items of iterator
+    ///
+    /// First item will be current, index will be **0** and remaining items are not consumed
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let tuple: DoubleBuffer<u8> = (0..2).collect();
+    ///
+    /// assert_eq!(tuple.current(), &0);
+    /// assert_eq!(tuple.next(), &1);
+    /// assert_eq!(tuple.index(), 0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if iterator produces less than two items
+    ///
+    /// ```should_panic
+    /// use bevy_double_res::DoubleBuffer;
+    /// let tuple: DoubleBuffer<u8> = std::iter::once(0).collect();
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut iter = iter.into_iter();
+        match (iter.next(), iter.next()) {
+            (Some(first), Some(second)) => Self::from_buffer([first, second], 0),
+            (Some(_), None) => panic!("DoubleBuffer requires two items, got 1"),
+            _ => panic!("DoubleBuffer requires two items, got 0"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> TryFrom<Vec<T>> for DoubleBuffer<T> {
+    type Error = LengthMismatch;
+
+    /// Create double buffer of **T** from vector of exactly two items
+    ///
+    /// First item will be current and index will be **0**
+    ///
+    /// Vector is a cloneable value too, so element type has to be known to pick this conversion
+    /// over infallible creation of **DoubleBuffer<Vec\<T>>**
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::{DoubleBuffer, LengthMismatch};
+    /// let tuple = DoubleBuffer::<u8>::try_from(vec![10, 20]).unwrap();
+    ///
+    /// assert_eq!(tuple.current(), &10);
+    /// assert_eq!(tuple.next(), &20);
+    /// assert_eq!(tuple.index(), 0);
+    ///
+    /// assert_eq!(DoubleBuffer::<u8>::try_from(vec![10]), Err(LengthMismatch::TooFew(1)));
+    /// assert_eq!(
+    ///     DoubleBuffer::<u8>::try_from(vec![10, 20, 30]),
+    ///     Err(LengthMismatch::TooMany(3))
+    /// );
+    /// ```
+    fn try_from(items: Vec<T>) -> Result<Self, Self::Error> {
+        let len = items.len();
+        match <[T; 2]>::try_from(items) {
+            Ok(buffer) => Ok(Self::from_buffer(buffer, 0)),
+            Err(_) if len < 2 => Err(LengthMismatch::TooFew(len)),
+            Err(_) => Err(LengthMismatch::TooMany(len)),
+        }
+    }
+}
+
+impl<T> Default for DoubleBuffer<T>
+where
+    T: Default,
+{
+    /// Create double buffer of **T** with default values of **T**
+    ///
+    /// Each copy is created with its own **T::default()** call, so **T** is not required to be
+    /// cloneable. Index will be **0**
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let tuple = DoubleBuffer::<(u8, u8)>::default();
+    ///
+    /// assert_eq!(tuple.current(), &(0, 0));
+    /// assert_eq!(tuple.next(), &(0, 0));
+    /// assert_eq!(tuple.index(), 0);
+    ///
+    /// #[derive(Default, Debug, PartialEq)]
+    /// struct UniqueHandle(u32);
+    ///
+    /// let handles = DoubleBuffer::<UniqueHandle>::default();
+    ///
+    /// assert_eq!(handles.current(), &UniqueHandle(0));
+    /// assert_eq!(handles.next(), &UniqueHandle(0));
+    /// ```
+    fn default() -> Self {
+        Self::from_fn(|_| T::default())
+    }
+}
+
+/// Type-safe copy index of [DoubleBuffer], **A** is **0** and **B** is **1**
+///
+/// # Example
+///
+/// ```
+/// use bevy_double_res::{IndexOutOfRange, Side};
+///
+/// assert_eq!(u8::from(Side::A), 0);
+/// assert_eq!(u8::from(Side::B), 1);
+///
+/// assert_eq!(Side::try_from(1), Ok(Side::B));
+/// assert_eq!(Side::try_from(2), Err(IndexOutOfRange(2)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Side {
+    /// Copy under index **0**
+    A,
+    /// Copy under index **1**
+    B,
+}
+
+impl From<Side> for u8 {
+    fn from(side: Side) -> Self {
+        match side {
+            Side::A => 0,
+            Side::B => 1,
+        }
+    }
+}
+
+impl TryFrom<u8> for Side {
+    type Error = IndexOutOfRange;
+
+    fn try_from(index: u8) -> Result<Self, Self::Error> {
+        match index {
+            0 => Ok(Side::A),
+            1 => Ok(Side::B),
+            _ => Err(IndexOutOfRange(index.into())),
+        }
+    }
+}
+
+/// Named references to current and next copies, returned by [`DoubleBuffer::view_mut`]
+#[derive(Debug)]
+pub struct CurrentNext<'a, T> {
+    /// Copy under current index
+    pub current: &'a T,
+    /// Copy under opposite of current index
+    pub next: &'a mut T,
+}
+
+/// Readonly half of [DoubleBuffer], returned by [`DoubleBuffer::split_rw`]
+#[derive(Debug)]
+pub struct Reader<'a, T> {
+    current: &'a T,
+}
+
+impl<'a, T> Reader<'a, T> {
+    /// Get readonly reference to current copy
+    pub fn current(&self) -> &T {
+        self.current
+    }
+}
+
+/// Writable half of [DoubleBuffer], returned by [`DoubleBuffer::split_rw`]
+#[derive(Debug)]
+pub struct Writer<'a, T> {
+    next: &'a mut T,
+}
+
+impl<'a, T> Writer<'a, T> {
+    /// Get mutable reference to next copy
+    pub fn next_mut(&mut self) -> &mut T {
+        self.next
+    }
+}
+
+/// View reading current and writing next copy of [DoubleBuffer], returned by
+/// [`DoubleBuffer::pipeline`]
+#[derive(Debug)]
+pub struct Pipeline<'a, T> {
+    buffer: &'a mut DoubleBuffer<T>,
+}
+
+impl<'a, T> Pipeline<'a, T> {
+    /// Get readonly reference to current copy
+    pub fn current(&self) -> &T {
+        self.buffer.current()
+    }
+
+    /// Get mutable reference to next copy
+    pub fn next_mut(&mut self) -> &mut T {
+        self.buffer.next_mut()
+    }
+
+    /// Swap buffer, making written copy current
+    pub fn commit(self) {
+        self.buffer.swap();
+    }
+}
+
+/// Deferred swap of [DoubleBuffer], returned by [`DoubleBuffer::defer_swap`]
+#[derive(Debug)]
+pub struct PendingSwap {
+    generation: u64,
+}
+
+impl PendingSwap {
+    /// Swap buffer, unless it was already swapped after the token was created or has no pending
+    /// swap
+    ///
+    /// Returns **true** if swap happened. Only generation and pending mark are checked, see
+    /// [`DoubleBuffer::defer_swap`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    ///
+    /// let pending = tuple.defer_swap();
+    /// tuple.swap();
+    ///
+    /// assert!(!pending.resolve(&mut tuple));
+    /// assert_eq!(tuple.index(), 1);
+    /// ```
+    ///
+    /// Pending mark left by dropped token is cleared by the next swap:
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    /// let mut other = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 1);
+    ///
+    /// drop(tuple.defer_swap());
+    /// tuple.swap();
+    ///
+    /// other.swap();
+    /// let pending = other.defer_swap();
+    ///
+    /// assert!(!pending.resolve(&mut tuple));
+    /// assert_eq!(tuple.index(), 1);
+    /// ```
+    pub fn resolve<T>(self, buffer: &mut DoubleBuffer<T>) -> bool {
+        buffer.swap_if(buffer.pending_swap && buffer.generation == self.generation)
+    }
+}
+
+/// Guard swapping [DoubleBuffer] on drop, returned by [`DoubleBuffer::swap_on_drop`]
+///
+/// Dereferences to the buffer itself
+#[derive(Debug)]
+pub struct SwapGuard<'a, T> {
+    buffer: &'a mut DoubleBuffer<T>,
+}
+
+impl<'a, T> Deref for SwapGuard<'a, T> {
+    type Target = DoubleBuffer<T>;
+
+    fn deref(&self) -> &Self::Target {
+        self.buffer
+    }
+}
+
+impl<'a, T> DerefMut for SwapGuard<'a, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.buffer
+    }
+}
+
+impl<'a, T> Drop for SwapGuard<'a, T> {
+    /// Swap guarded buffer
+    fn drop(&mut self) {
+        self.buffer.swap();
+    }
+}
+
+/// Error of accessing [DoubleBuffer] with index outside of range \[0, 1]
+///
+/// Contains the invalid index
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexOutOfRange(pub usize);
+
+impl fmt::Display for IndexOutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DoubleBuffer index must be 0 or 1, got {}", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IndexOutOfRange {}
+
+/// Error of creating [DoubleBuffer] from collection without exactly two items
+///
+/// Contains the actual number of items
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthMismatch {
+    /// Less than two items were provided
+    TooFew(usize),
+    /// More than two items were provided
+    TooMany(usize),
+}
+
+impl fmt::Display for LengthMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LengthMismatch::TooFew(len) | LengthMismatch::TooMany(len) => {
+                write!(f, "DoubleBuffer requires exactly 2 items, got {}", len)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LengthMismatch {}
@@ -0,0 +1,124 @@
+//! Double-buffering implementation for small [Copy] types
+//!
+//! Implementation uses two separate copies, keeping current one always in the first slot
+
+/// Enables double-buffering of small [Copy] data by physically swapping two copies
+///
+/// Unlike [DoubleBuffer](crate::DoubleBuffer), there is no current copy index: current copy
+/// always lives in the first slot and [`DoubleBufferCopy::swap`] moves data instead. This makes
+/// [`DoubleBufferCopy::current`] a fixed-slot read at the cost of copying both values on every
+/// swap, which only pays off for tiny payloads read far more often than swapped
+///
+/// # Example
+///
+/// ```
+/// use bevy_double_res::{DoubleBuffer, DoubleBufferCopy};
+/// let mut indexed = DoubleBuffer::from_buffer([(10, 20), (0, 0)], 0);
+/// let mut copied = DoubleBufferCopy::new_with((10, 20), (0, 0));
+///
+/// for _ in 0..3 {
+///     indexed.apply(|current, next| {
+///         next.0 = current.1;
+///         next.1 = current.0 + 1;
+///     });
+///     indexed.swap();
+///
+///     copied.apply(|current, next| {
+///         next.0 = current.1;
+///         next.1 = current.0 + 1;
+///     });
+///     copied.swap();
+///
+///     assert_eq!(copied.current(), indexed.current());
+///     assert_eq!(copied.next(), indexed.next());
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DoubleBufferCopy<T>
+where
+    T: Copy,
+{
+    buffer: [T; 2],
+}
+
+impl<T> DoubleBufferCopy<T>
+where
+    T: Copy,
+{
+    /// Create double buffer of **T** from one copy of **T**
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBufferCopy;
+    /// let tuple = DoubleBufferCopy::new((10, 20));
+    ///
+    /// assert_eq!(tuple.current(), &(10, 20));
+    /// assert_eq!(tuple.next(), &(10, 20));
+    /// ```
+    pub fn new(value: T) -> Self {
+        Self::new_with(value, value)
+    }
+
+    /// Create double buffer of **T** from current and next copies of **T**
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBufferCopy;
+    /// let tuple = DoubleBufferCopy::new_with((10, 20), (20, 10));
+    ///
+    /// assert_eq!(tuple.current(), &(10, 20));
+    /// assert_eq!(tuple.next(), &(20, 10));
+    /// ```
+    pub fn new_with(current: T, next: T) -> Self {
+        Self {
+            buffer: [current, next],
+        }
+    }
+
+    /// Get readonly current copy reference
+    pub fn current(&self) -> &T {
+        &self.buffer[0]
+    }
+
+    /// Get mutable current copy reference
+    pub fn current_mut(&mut self) -> &mut T {
+        &mut self.buffer[0]
+    }
+
+    /// Get readonly next copy reference
+    pub fn next(&self) -> &T {
+        &self.buffer[1]
+    }
+
+    /// Get mutable next copy reference
+    pub fn next_mut(&mut self) -> &mut T {
+        &mut self.buffer[1]
+    }
+
+    /// Swaps current and next copies by moving data between slots
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBufferCopy;
+    /// let mut tuple = DoubleBufferCopy::new_with((10, 20), (20, 10));
+    ///
+    /// tuple.swap();
+    ///
+    /// assert_eq!(tuple.current(), &(20, 10));
+    /// assert_eq!(tuple.next(), &(10, 20));
+    /// ```
+    pub fn swap(&mut self) {
+        self.buffer.swap(0, 1);
+    }
+
+    /// Applies function to operate on current and next copies
+    ///
+    /// Same as [`DoubleBuffer::apply`](crate::DoubleBuffer::apply)
+    pub fn apply<Res>(&mut self, f: impl FnOnce(&T, &mut T) -> Res) -> Res {
+        let [current, next] = &mut self.buffer;
+        f(current, next)
+    }
+}
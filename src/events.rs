@@ -0,0 +1,61 @@
+//! Provides double-buffered event channel with one frame delayed delivery
+
+use crate::DoubleBuffer;
+
+/// Event channel backed by [DoubleBuffer] of [Vec<E>]
+///
+/// Events are written into next copy and become readable from current copy only after
+/// [`DoubleEvents::swap`], which makes delivery deterministic regardless of system order
+///
+/// # Example
+///
+/// ```
+/// use bevy_double_res::DoubleEvents;
+/// let mut events = DoubleEvents::default();
+///
+/// events.write_next("jump");
+///
+/// assert!(events.current().is_empty());
+///
+/// events.swap();
+///
+/// assert_eq!(events.current(), &["jump"]);
+///
+/// events.swap();
+///
+/// assert!(events.current().is_empty());
+/// ```
+#[derive(Debug)]
+pub struct DoubleEvents<E> {
+    buffer: DoubleBuffer<Vec<E>>,
+}
+
+impl<E> DoubleEvents<E> {
+    /// Write event to be read after next swap
+    pub fn write_next(&mut self, event: E) {
+        self.buffer.next_mut().push(event);
+    }
+
+    /// Read events committed by last swap
+    pub fn current(&self) -> &[E] {
+        self.buffer.current()
+    }
+
+    /// Commit written events, dropping the ones committed by previous swap
+    pub fn swap(&mut self) {
+        self.buffer.swap();
+        self.buffer.next_mut().clear();
+    }
+}
+
+impl<E> Default for DoubleEvents<E> {
+    /// Create channel without events
+    fn default() -> Self {
+        Self {
+            buffer: DoubleBuffer::from_fn(|_| Vec::new()),
+        }
+    }
+}
+
+#[cfg(feature = "bevy")]
+impl<E> bevy_ecs::system::Resource for DoubleEvents<E> where E: Send + Sync + 'static {}
@@ -0,0 +1,76 @@
+//! Provides group of buffers swapped together
+
+use crate::DoubleBuffer;
+
+/// Group of [DoubleBuffer]s of the same type, swapped all at once
+///
+/// Useful to store many buffers, e.g. per-layer simulation grids, in a single resource
+///
+/// # Example
+///
+/// ```
+/// use bevy_double_res::{DoubleBuffer, DoubleBufferGroup};
+/// let mut layers = DoubleBufferGroup::from(vec![
+///     DoubleBuffer::new(10),
+///     DoubleBuffer::new(20),
+///     DoubleBuffer::from_buffer([30, 40], 1),
+/// ]);
+///
+/// layers.swap_all();
+///
+/// assert_eq!(layers.len(), 3);
+/// assert_eq!(layers.get(0).map(DoubleBuffer::index), Some(1));
+/// assert_eq!(layers.get(1).map(DoubleBuffer::index), Some(1));
+/// assert_eq!(layers.get(2).map(DoubleBuffer::index), Some(0));
+/// assert_eq!(layers.get(3), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DoubleBufferGroup<T> {
+    buffers: Vec<DoubleBuffer<T>>,
+}
+
+impl<T> DoubleBufferGroup<T> {
+    /// Swap every buffer of the group
+    pub fn swap_all(&mut self) {
+        self.buffers.iter_mut().for_each(DoubleBuffer::swap);
+    }
+
+    /// Get readonly buffer reference by its position in group
+    pub fn get(&self, i: usize) -> Option<&DoubleBuffer<T>> {
+        self.buffers.get(i)
+    }
+
+    /// Get mutable buffer reference by its position in group
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut DoubleBuffer<T>> {
+        self.buffers.get_mut(i)
+    }
+
+    /// Get number of buffers in group
+    pub fn len(&self) -> usize {
+        self.buffers.len()
+    }
+
+    /// Check if group has no buffers
+    pub fn is_empty(&self) -> bool {
+        self.buffers.is_empty()
+    }
+}
+
+impl<T> From<Vec<DoubleBuffer<T>>> for DoubleBufferGroup<T> {
+    /// Create group from buffers, keeping their order
+    fn from(buffers: Vec<DoubleBuffer<T>>) -> Self {
+        Self { buffers }
+    }
+}
+
+impl<T> Default for DoubleBufferGroup<T> {
+    /// Create group without buffers
+    fn default() -> Self {
+        Self {
+            buffers: Vec::new(),
+        }
+    }
+}
+
+#[cfg(feature = "bevy")]
+impl<T> bevy_ecs::system::Resource for DoubleBufferGroup<T> where T: Send + Sync + 'static {}
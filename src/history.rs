@@ -0,0 +1,100 @@
+//! Provides double buffer remembering past states
+//!
+//! Implementation keeps a fixed ring of **K** snapshots, so no allocation happens
+
+use crate::DoubleBuffer;
+use core::ops::Deref;
+
+/// Wrapper of [DoubleBuffer] which remembers last **K** current copies replaced by swaps
+///
+/// Every [`DoubleBufferWithHistory::swap`] clones current copy into the ring before swapping,
+/// dropping the oldest snapshot once there are **K** of them
+///
+/// # Example
+///
+/// ```
+/// use bevy_double_res::{DoubleBuffer, DoubleBufferWithHistory};
+/// let mut counter = DoubleBufferWithHistory::<_, 2>::new(DoubleBuffer::new(0));
+///
+/// for _ in 0..4 {
+///     counter.apply(|current, next| *next = current + 1);
+///     counter.swap();
+/// }
+///
+/// assert_eq!(counter.current(), &4);
+/// assert_eq!(counter.history().collect::<Vec<_>>(), [&3, &2]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DoubleBufferWithHistory<T, const K: usize>
+where
+    T: Clone,
+{
+    buffer: DoubleBuffer<T>,
+    history: [Option<T>; K],
+    head: usize,
+}
+
+impl<T, const K: usize> DoubleBufferWithHistory<T, K>
+where
+    T: Clone,
+{
+    /// Wrap buffer with empty history
+    pub fn new(buffer: DoubleBuffer<T>) -> Self {
+        Self {
+            buffer,
+            history: core::array::from_fn(|_| None),
+            head: 0,
+        }
+    }
+
+    /// Same as [`DoubleBuffer::apply`]
+    pub fn apply<Res>(&mut self, f: impl FnOnce(&T, &mut T) -> Res) -> Res {
+        self.buffer.apply(f)
+    }
+
+    /// Remember current copy, then swap the buffer
+    pub fn swap(&mut self) {
+        if K > 0 {
+            self.history[self.head] = Some(self.buffer.current().clone());
+            self.head = (self.head + 1) % K;
+        }
+        self.buffer.swap();
+    }
+
+    /// Iterate over remembered copies, newest first
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::{DoubleBuffer, DoubleBufferWithHistory};
+    /// let mut names = DoubleBufferWithHistory::<_, 3>::new(DoubleBuffer::new_with("a", "b"));
+    ///
+    /// assert_eq!(names.history().count(), 0);
+    ///
+    /// names.swap();
+    ///
+    /// assert_eq!(names.history().collect::<Vec<_>>(), [&"a"]);
+    /// ```
+    pub fn history(&self) -> impl Iterator<Item = &T> + '_ {
+        (1..=K).map_while(move |age| self.history[(self.head + K - age) % K].as_ref())
+    }
+
+    /// Unwrap buffer, forgetting history
+    pub fn into_inner(self) -> DoubleBuffer<T> {
+        self.buffer
+    }
+}
+
+impl<T, const K: usize> Deref for DoubleBufferWithHistory<T, K>
+where
+    T: Clone,
+{
+    type Target = DoubleBuffer<T>;
+
+    /// Dereference to wrapped buffer
+    ///
+    /// Only readonly access is provided, so swaps can't bypass history
+    fn deref(&self) -> &Self::Target {
+        &self.buffer
+    }
+}
@@ -4,6 +4,9 @@ use crate::DoubleBuffer;
 
 /// Helper trait for more convenient buffer creation
 ///
+/// Works through reference, so **value** is cloned twice and stays usable afterwards. See
+/// [IntoDoubleBufferOwned] for version consuming **value**
+///
 /// # Example
 ///
 /// ```
@@ -59,6 +62,31 @@ where
     /// in mind this fact when your system panics about missing of **T**, because it might be **&T**
     /// existing in the world.
     fn into_double_buf(self) -> DoubleBuffer<Self::Item>;
+
+    /// Method for buffer creation with distinct second copy
+    ///
+    /// Original value becomes first copy, second one is produced by **f** from it. Index will be
+    /// **0**. Value is cloned only once, for the first copy
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::{DoubleBuffer, IntoDoubleBuffer};
+    /// let tuple = (10, 20).into_double_buf_with(|(a, b)| (a * 2, b * 2));
+    ///
+    /// assert_eq!(tuple.current(), &(10, 20));
+    /// assert_eq!(tuple.next(), &(20, 40));
+    /// assert_eq!(tuple.index(), 0);
+    /// ```
+    fn into_double_buf_with(
+        self,
+        f: impl FnOnce(&Self::Item) -> Self::Item,
+    ) -> DoubleBuffer<Self::Item> {
+        let mut buffer = self.into_double_buf();
+        let next = f(buffer.current());
+        buffer.replace_next(next);
+        buffer
+    }
 }
 
 impl<T> IntoDoubleBuffer for &T
@@ -67,6 +95,106 @@ where
 {
     type Item = T;
     fn into_double_buf(self) -> DoubleBuffer<Self::Item> {
-        DoubleBuffer::new(self.to_owned())
+        DoubleBuffer::new(T::clone(self))
+    }
+
+    fn into_double_buf_with(self, f: impl FnOnce(&T) -> T) -> DoubleBuffer<T> {
+        let current = T::clone(self);
+        let next = f(&current);
+        DoubleBuffer::new_with(current, next)
+    }
+}
+
+/// Helper trait for buffer creation consuming the value
+///
+/// Unlike [IntoDoubleBuffer], **value** is moved into the buffer and cloned only once. Use it
+/// when **value** is not needed afterwards
+///
+/// # Example
+///
+/// ```
+/// use bevy_double_res::{DoubleBuffer, IntoDoubleBuffer, IntoDoubleBufferOwned};
+/// let name = String::from("name");
+///
+/// let borrowed = name.into_double_buf();
+/// let owned = name.into_double_buf_owned();
+///
+/// assert_eq!(borrowed, owned);
+/// assert_eq!(owned.current(), "name");
+/// assert_eq!(owned.next(), "name");
+/// ```
+///
+/// # Warning
+///
+/// Unlike [IntoDoubleBuffer], this trait does not unwrap references: calling it on **&T** gives
+/// [DoubleBuffer<&T>]
+pub trait IntoDoubleBufferOwned: Clone {
+    /// Method for buffer creation consuming the value
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::{DoubleBuffer, IntoDoubleBufferOwned};
+    /// let names = vec![String::from("first"), String::from("second")].into_double_buf_owned();
+    ///
+    /// assert_eq!(names.current().len(), 2);
+    /// assert_eq!(names.next().len(), 2);
+    /// ```
+    fn into_double_buf_owned(self) -> DoubleBuffer<Self> {
+        DoubleBuffer::new(self)
     }
 }
+
+impl<T> IntoDoubleBufferOwned for T where T: Clone {}
+
+/// Extension trait lifting owned state values into buffer
+///
+/// Short alias of [`IntoDoubleBufferOwned::into_double_buf_owned`] reading well on state enums.
+/// Owned values only: calling it on **&T** gives [DoubleBuffer<&T>]
+///
+/// Not included in [prelude](crate::prelude), import it explicitly where `.double()` is wanted
+///
+/// # Example
+///
+/// ```
+/// use bevy_double_res::{DoubleBuffer, DoubleStateExt};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// enum Phase {
+///     Idle,
+///     Running { speed: u8 },
+/// }
+///
+/// let mut phase = Phase::Idle.double();
+/// *phase.next_mut() = Phase::Running { speed: 3 };
+/// phase.swap();
+///
+/// assert_eq!(phase.current(), &Phase::Running { speed: 3 });
+/// assert_eq!(phase.next(), &Phase::Idle);
+/// ```
+pub trait DoubleStateExt: IntoDoubleBufferOwned {
+    /// Create buffer with both copies equal to **self**, index will be **0**
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::{DoubleBuffer, DoubleStateExt};
+    ///
+    /// #[derive(Clone, Copy, Debug, PartialEq)]
+    /// enum Light {
+    ///     Red,
+    ///     Green,
+    /// }
+    ///
+    /// let light = Light::Green.double();
+    ///
+    /// assert_eq!(light.current(), &Light::Green);
+    /// assert_eq!(light.next(), &Light::Green);
+    /// assert_eq!(light.index(), 0);
+    /// ```
+    fn double(self) -> DoubleBuffer<Self> {
+        self.into_double_buf_owned()
+    }
+}
+
+impl<T> DoubleStateExt for T where T: IntoDoubleBufferOwned {}
@@ -1,19 +1,147 @@
 //! # Straightforward double-buffering implementation for [bevy engine](https://bevyengine.org/)
 //!
-//! Consists of main struct [DoubleBuffer], helper auto trait [IntoDoubleBuffer] and two aliases
-//! [DoubleRes] and [DoubleResMut]
+//! Consists of main type [DoubleBuffer], its generalization [MultiBuffer], helper auto trait
+//! [IntoDoubleBuffer]
+#![cfg_attr(
+    feature = "bevy",
+    doc = "and two aliases [DoubleRes] and [DoubleResMut]"
+)]
+//!
+//! Tiny [Copy] payloads may use [DoubleBufferCopy], which swaps data instead of index, and
+//! [DoubleBufferWithHistory] remembers a few past states for replay and debugging
+#![cfg_attr(
+    feature = "bevy",
+    doc = "
+Per-entity buffers are provided by [DoubleComponent] with two aliases [DoubleQuery] and
+[DoubleQueryMut]
+
+Systems may take [AutoSwap] instead of [DoubleResMut] to swap buffer automatically when they
+finish"
+)]
+#![cfg_attr(
+    feature = "std",
+    doc = "
+Events with one frame delayed delivery are provided by [DoubleEvents], many buffers of the
+same type can be swapped at once with [DoubleBufferGroup], and [DoubleBufferWithSwapHook]
+derives next copy from current one on every swap"
+)]
+//!
+//! Aliases, components and systems are provided by default **bevy** feature. Without it crate is
+//! `no_std`, so buffers can be used outside of bevy, e.g. in embedded simulation code:
+//!
+//! ```
+//! use bevy_double_res::{DoubleBuffer, IntoDoubleBuffer};
+//! let mut tuple = (10, 20).into_double_buf();
+//!
+//! tuple.apply(|current, next| {
+//!     next.0 = current.1;
+//!     next.1 = current.0;
+//! });
+//! tuple.swap();
+//!
+//! assert_eq!(tuple.current(), &(20, 10));
+//! ```
+//!
+//! Enable **serde** feature to serialize and deserialize buffers and **reflect** feature to make
+//! them visible to bevy scenes and inspector tooling. Enable **bevy_app** feature to get
+//! `DoubleBufferPlugin` swapping buffers automatically, or register many of them at once with
+//! `init_double_resource`
+//!
+//! Enable **atomic** feature to get `AtomicDoubleBuffer`, publishing states across threads with
+//! atomic pointer swap instead of `&mut` access. Enable **bytemuck** feature to create large
+//! scratch buffers of plain data with `DoubleBuffer::zeroed` instead of cloning
+//!
+//! Enable **derive** feature to double-buffer only some fields of a struct with
+//! `DoubleBuffered` derive. Fields marked with `#[double]` get two copies, others are shared:
+//!
+//! ```
+//! # #[cfg(feature = "derive")]
+//! # {
+//! use bevy_double_res::DoubleBuffered;
+//!
+//! #[derive(DoubleBuffered)]
+//! struct Body {
+//!     #[double]
+//!     position: (i32, i32),
+//!     mass: i32,
+//! }
+//!
+//! let mut body = BodyDouble::from(Body { position: (0, 0), mass: 10 });
+//!
+//! body.apply(|current, next| next.position.0 = current.position.0 + current.mass);
+//! body.swap();
+//!
+//! assert_eq!(body.position.current(), &(10, 0));
+//!
+//! body.mass = 20;
+//! body.swap();
+//!
+//! body.apply(|current, next| {
+//!     assert_eq!(current.position, &(0, 0));
+//!     assert_eq!(current.mass, &20);
+//!     assert_eq!(next.position, &mut (10, 0));
+//! });
+//! # }
+//! ```
 //!
 //! *Crate was developed by solving author's personal problems so you can expect some bugs*
 
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(missing_docs)]
 
+#[cfg(feature = "atomic")]
+pub mod atomic;
+#[cfg(feature = "bevy")]
+pub mod auto_swap;
+#[cfg(feature = "bevy")]
+pub mod component;
 pub mod double_buffer;
+pub mod double_buffer_copy;
+#[cfg(feature = "std")]
+pub mod events;
+#[cfg(feature = "std")]
+pub mod group;
+pub mod history;
 pub mod into_double_buffer;
+pub mod multi_buffer;
+#[cfg(feature = "bevy_app")]
+pub mod plugin;
+#[cfg(feature = "bevy")]
+pub mod res_ext;
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "std")]
+pub mod swap_hook;
+pub mod swap_strategy;
+#[cfg(feature = "bevy")]
+pub mod systems;
 
+#[cfg(feature = "atomic")]
+pub use atomic::*;
+#[cfg(feature = "bevy")]
+pub use auto_swap::*;
+#[cfg(feature = "bevy")]
+pub use component::*;
 pub use double_buffer::*;
+pub use double_buffer_copy::*;
+#[cfg(feature = "std")]
+pub use events::*;
+#[cfg(feature = "std")]
+pub use group::*;
+pub use history::*;
 pub use into_double_buffer::*;
+pub use multi_buffer::*;
+#[cfg(feature = "bevy")]
+pub use res_ext::*;
+#[cfg(feature = "std")]
+pub use swap_hook::*;
+pub use swap_strategy::*;
 
+#[cfg(feature = "derive")]
+pub use bevy_double_res_derive::DoubleBuffered;
+
+#[cfg(feature = "bevy")]
 use bevy_ecs::prelude::*;
 
 /// Alias for [Res] of [DoubleBuffer<T>]
@@ -36,6 +164,7 @@ use bevy_ecs::prelude::*;
 ///     // ...
 /// }
 /// ```
+#[cfg(feature = "bevy")]
 pub type DoubleRes<'w, T> = Res<'w, DoubleBuffer<T>>;
 
 /// Alias for [ResMut] of [DoubleBuffer<T>]
@@ -58,10 +187,68 @@ pub type DoubleRes<'w, T> = Res<'w, DoubleBuffer<T>>;
 ///     // ...
 /// }
 /// ```
+///
+/// # Calling buffer methods on the guard
+///
+/// [DoubleResExt] and [DoubleResMutExt] from the prelude forward buffer methods to the guard, so
+/// they surface directly on [DoubleResMut] instead of going through [ResMut]'s deref
+///
+/// ```
+/// use bevy::ecs::schedule::Stage;
+/// use bevy::prelude::*;
+/// use bevy_double_res::prelude::*;
+///
+/// fn fade_system(mut colors: DoubleResMut<[u8; 3]>) {
+///     assert_eq!(colors.current(), &[255, 128, 0]);
+///
+///     colors.apply(|current, next| {
+///         for (current, next) in current.iter().zip(next) {
+///             *next = current / 2;
+///         }
+///     });
+///     colors.swap();
+///
+///     assert_eq!(colors.next(), &[255, 128, 0]);
+/// }
+///
+/// let mut world = World::new();
+/// world.insert_resource(DoubleBuffer::new([255u8, 128, 0]));
+///
+/// SystemStage::single_threaded()
+///     .with_system(fade_system)
+///     .run(&mut world);
+///
+/// assert_eq!(world.resource::<DoubleBuffer<[u8; 3]>>().current(), &[127, 64, 0]);
+/// ```
+#[cfg(feature = "bevy")]
 pub type DoubleResMut<'w, T> = ResMut<'w, DoubleBuffer<T>>;
 
 pub mod prelude {
     //! Provides all crate items
 
-    pub use super::{DoubleBuffer, DoubleRes, DoubleResMut, IntoDoubleBuffer};
+    pub use super::{
+        CopyForward, CurrentNext, DoubleBuffer, DoubleBufferCopy, DoubleBufferWithHistory,
+        FlipIndex, IndexOutOfRange, IntoDoubleBuffer, IntoDoubleBufferOwned, LengthMismatch,
+        MultiBuffer, PendingSwap, Pipeline, Reader, Side, SwapContents, SwapGuard, SwapStrategy,
+        Writer,
+    };
+
+    #[cfg(feature = "bevy")]
+    pub use super::systems::{swap_double_buffer, swap_double_events};
+    #[cfg(feature = "bevy")]
+    pub use super::{
+        AutoSwap, DoubleComponent, DoubleQuery, DoubleQueryMut, DoubleRes, DoubleResExt,
+        DoubleResMut, DoubleResMutExt,
+    };
+    #[cfg(feature = "std")]
+    pub use super::{DoubleBufferGroup, DoubleBufferWithSwapHook, DoubleEvents};
+
+    #[cfg(feature = "bevy_app")]
+    pub use super::plugin::{
+        DoubleBufferAppExt, DoubleBufferPlugin, DoubleBufferStage, DoubleBufferSwapSet,
+    };
+    #[cfg(feature = "atomic")]
+    pub use super::AtomicDoubleBuffer;
+    #[cfg(feature = "derive")]
+    pub use super::DoubleBuffered;
 }
@@ -0,0 +1,1088 @@
+//! Straightforward multi-buffering implementation
+//!
+//! Implementation uses **N** separate copies and current copy index
+
+use crate::IndexOutOfRange;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Deref, DerefMut, Index, IndexMut};
+
+/// Enables multi-buffering of your data by storing **N** separate copies and current copy index
+///
+/// Copies are rotated with [`MultiBuffer::advance`], wrapping around after the last one
+///
+/// # Example
+///
+/// ```
+/// use bevy_double_res::MultiBuffer;
+/// let mut snapshots = MultiBuffer::from_array([10, 20, 30], 0);
+///
+/// assert_eq!(snapshots.current(), &10);
+///
+/// snapshots.advance();
+/// *snapshots.current_mut() = 40;
+///
+/// assert_eq!(snapshots.current(), &40);
+/// assert_eq!(snapshots.nth_back(1), &10);
+/// assert_eq!(snapshots.nth_back(2), &30);
+/// ```
+///
+/// # Reflection
+///
+/// With **reflect** feature enabled buffer implements `Reflect`, exposing `buffer`, `index`,
+/// `generation` and `dirty` as reflected fields
+///
+/// ```
+/// # #[cfg(feature = "reflect")]
+/// # {
+/// use bevy::prelude::*;
+/// use bevy::reflect::GetField;
+/// use bevy_double_res::DoubleBuffer;
+///
+/// let mut tuple = DoubleBuffer::from_buffer([10u8, 20], 0);
+/// tuple.swap();
+///
+/// assert_eq!(tuple.get_field::<usize>("index"), Some(&1));
+/// assert_eq!(tuple.get_field::<[u8; 2]>("buffer"), Some(&[10, 20]));
+///
+/// App::new().register_type::<DoubleBuffer<u8>>();
+/// # }
+/// ```
+#[cfg_attr(
+    feature = "reflect",
+    derive(bevy_reflect::Reflect, bevy_reflect::FromReflect)
+)]
+pub struct MultiBuffer<T, const N: usize>
+where
+    T: Sized,
+{
+    pub(crate) buffer: [T; N],
+    pub(crate) index: usize,
+    pub(crate) generation: u64,
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
+    pub(crate) swap_baseline: u64,
+    pub(crate) dirty: bool,
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
+    pub(crate) pending_swap: bool,
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
+    pub(crate) labels: Option<[&'static str; N]>,
+}
+
+impl<T, const N: usize> MultiBuffer<T, N> {
+    /// Every index is taken modulo **N**, so buffer without copies is rejected at compile time
+    const _ASSERT: () = assert!(N > 0, "MultiBuffer must have at least one copy");
+
+    /// Construct buffer manually using **N** copies and index
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::MultiBuffer;
+    /// let snapshots = MultiBuffer::from_array([10, 20, 30], 2);
+    ///
+    /// assert_eq!(snapshots.current(), &30);
+    /// ```
+    ///
+    /// Buffer without copies does not compile:
+    ///
+    /// ```compile_fail
+    /// use bevy_double_res::MultiBuffer;
+    /// let empty = MultiBuffer::<i32, 0>::from_array([], 0);
+    /// ```
+    pub fn from_array(buffer: [T; N], index: usize) -> Self {
+        let () = Self::_ASSERT;
+        Self {
+            buffer,
+            index,
+            generation: 0,
+            swap_baseline: 0,
+            dirty: false,
+            pending_swap: false,
+            labels: None,
+        }
+    }
+
+    /// Construct buffer by calling function with position of every copy
+    ///
+    /// Index will be **0**. Works for types that are expensive or impossible to clone, same as
+    /// [core::array::from_fn]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let tuple = DoubleBuffer::from_fn(|i| vec![i; 3]);
+    ///
+    /// assert_eq!(tuple.current(), &vec![0, 0, 0]);
+    /// assert_eq!(tuple.next(), &vec![1, 1, 1]);
+    /// assert_eq!(tuple.index(), 0);
+    /// ```
+    pub fn from_fn(f: impl FnMut(usize) -> T) -> Self {
+        Self::from_array(core::array::from_fn(f), 0)
+    }
+
+    /// Access underlying buffer for reading
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::MultiBuffer;
+    /// let snapshots = MultiBuffer::from_array([10, 20, 30], 0);
+    ///
+    /// assert_eq!(snapshots.buffer(), &[10, 20, 30]);
+    /// ```
+    pub fn buffer(&self) -> &[T; N] {
+        &self.buffer
+    }
+
+    /// Copy underlying buffer out, without consuming the buffer
+    ///
+    /// Owned counterpart of [`MultiBuffer::buffer`], order does **not** depend on current index
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let tuple: DoubleBuffer<(u8, u8)> = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 1);
+    ///
+    /// let [first, second] = tuple.to_array();
+    ///
+    /// assert_eq!(first, tuple[0]);
+    /// assert_eq!(second, tuple[1]);
+    /// ```
+    pub fn to_array(&self) -> [T; N]
+    where
+        T: Copy,
+    {
+        self.buffer
+    }
+
+    /// Access underlying buffer for mutation
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::MultiBuffer;
+    /// let mut snapshots = MultiBuffer::from_array([10, 20, 30], 0);
+    ///
+    /// snapshots.buffer_mut()[2] = 40;
+    ///
+    /// assert_eq!(snapshots.buffer(), &[10, 20, 40]);
+    /// ```
+    pub fn buffer_mut(&mut self) -> &mut [T; N] {
+        self.dirty = true;
+        &mut self.buffer
+    }
+
+    /// Get readonly copy reference by its position in underlying buffer
+    ///
+    /// Returns **None** if slot is not less than **N**, non-panicking version of indexing
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    ///
+    /// assert_eq!(tuple.get(1), Some(&(20, 10)));
+    /// assert_eq!(tuple.get(2), None);
+    /// ```
+    pub fn get(&self, slot: usize) -> Option<&T> {
+        self.buffer.get(slot)
+    }
+
+    /// Get mutable copy reference by its position in underlying buffer
+    ///
+    /// Returns **None** if slot is not less than **N**, non-panicking version of indexing. Buffer
+    /// is marked [dirty](MultiBuffer::is_dirty) only if copy exists
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    ///
+    /// assert_eq!(tuple.get_mut(2), None);
+    /// assert!(!tuple.is_dirty());
+    ///
+    /// if let Some(copy) = tuple.get_mut(1) {
+    ///     copy.0 = 999;
+    /// }
+    ///
+    /// assert_eq!(tuple.next(), &(999, 10));
+    /// assert!(tuple.is_dirty());
+    /// ```
+    pub fn get_mut(&mut self, slot: usize) -> Option<&mut T> {
+        let copy = self.buffer.get_mut(slot);
+        if copy.is_some() {
+            self.dirty = true;
+        }
+        copy
+    }
+
+    /// Access underlying current copy index
+    ///
+    /// [DoubleBuffer](crate::DoubleBuffer) also provides
+    /// [`DoubleBuffer::index`](crate::DoubleBuffer::index) returning **u8**
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::MultiBuffer;
+    /// let mut snapshots = MultiBuffer::from_array([10, 20, 30], 0);
+    ///
+    /// snapshots.advance();
+    /// snapshots.advance();
+    ///
+    /// assert_eq!(snapshots.current_index(), 2);
+    ///
+    /// snapshots.advance();
+    ///
+    /// assert_eq!(snapshots.current_index(), 0);
+    /// ```
+    pub fn current_index(&self) -> usize {
+        self.index
+    }
+
+    /// Get number of rotations made since construction
+    ///
+    /// Incremented by every [`MultiBuffer::advance`] and so by every
+    /// [`DoubleBuffer::swap`](crate::DoubleBuffer::swap), wrapping around on overflow. Useful to
+    /// invalidate caches built from previous copies
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    ///
+    /// assert_eq!(tuple.generation(), 0);
+    ///
+    /// tuple.swap();
+    /// tuple.swap();
+    /// tuple.swap();
+    ///
+    /// assert_eq!(tuple.generation(), 3);
+    /// assert_eq!(tuple.index(), 1);
+    /// ```
+    ///
+    /// Generation is not compared by [PartialEq] and is not serialized, same for
+    /// [dirty flag](MultiBuffer::is_dirty)
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Get number of rotations since creation or last [`MultiBuffer::reset_swap_counter`]
+    ///
+    /// Unlike [generation](MultiBuffer::generation), counter can be reset, e.g. every frame to
+    /// profile how often buffer is swapped
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    ///
+    /// for _ in 0..5 {
+    ///     tuple.swap();
+    /// }
+    ///
+    /// assert_eq!(tuple.swaps_since_reset(), 5);
+    ///
+    /// tuple.reset_swap_counter();
+    ///
+    /// assert_eq!(tuple.swaps_since_reset(), 0);
+    /// assert_eq!(tuple.generation(), 5);
+    /// ```
+    pub fn swaps_since_reset(&self) -> u64 {
+        self.generation.wrapping_sub(self.swap_baseline)
+    }
+
+    /// Reset counter returned by [`MultiBuffer::swaps_since_reset`], generation stays unchanged
+    pub fn reset_swap_counter(&mut self) {
+        self.swap_baseline = self.generation;
+    }
+
+    /// Check if any copy was mutably accessed since last rotation
+    ///
+    /// Set by every method giving mutable access to copies, such as
+    /// [`MultiBuffer::current_mut`] or [`DoubleBuffer::apply`](crate::DoubleBuffer::apply), and
+    /// reset by [`MultiBuffer::advance`] and so by
+    /// [`DoubleBuffer::swap`](crate::DoubleBuffer::swap). Unlike bevy change detection, taking
+    /// `ResMut` alone does not set it, so systems may skip work when nothing was written
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    ///
+    /// assert!(!tuple.is_dirty());
+    ///
+    /// tuple.next_mut().0 = 999;
+    ///
+    /// assert!(tuple.is_dirty());
+    ///
+    /// tuple.swap();
+    ///
+    /// assert!(!tuple.is_dirty());
+    /// ```
+    ///
+    /// This is a heuristic: access is tracked, not actual changes of values
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Get readonly copy reference under current index
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::MultiBuffer;
+    /// let mut snapshots = MultiBuffer::from_array([10, 20, 30], 0);
+    ///
+    /// assert_eq!(snapshots.current(), &10);
+    ///
+    /// snapshots.advance();
+    ///
+    /// assert_eq!(snapshots.current(), &20);
+    /// ```
+    pub fn current(&self) -> &T {
+        self.debug_check_index();
+        &self.buffer[self.index]
+    }
+
+    /// Get mutable copy reference under current index
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::MultiBuffer;
+    /// let mut snapshots = MultiBuffer::from_array([10, 20, 30], 0);
+    ///
+    /// *snapshots.current_mut() = 999;
+    ///
+    /// assert_eq!(snapshots.current(), &999);
+    ///
+    /// snapshots.advance();
+    ///
+    /// assert_eq!(snapshots.current(), &20);
+    /// ```
+    pub fn current_mut(&mut self) -> &mut T {
+        self.debug_check_index();
+        self.dirty = true;
+        &mut self.buffer[self.index]
+    }
+
+    /// Replace copy under current index, returning the old one
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([vec![1, 2], vec![3, 4]], 0);
+    ///
+    /// let old = tuple.replace_current(vec![5, 6]);
+    ///
+    /// assert_eq!(old, vec![1, 2]);
+    /// assert_eq!(tuple.current(), &vec![5, 6]);
+    /// assert_eq!(tuple.next(), &vec![3, 4]);
+    /// ```
+    pub fn replace_current(&mut self, value: T) -> T {
+        core::mem::replace(self.current_mut(), value)
+    }
+
+    /// Get readonly copy reference **k** rotation steps behind current index
+    ///
+    /// Wraps around modulo **N**, so `nth_back(0)` is the current copy
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::MultiBuffer;
+    /// let snapshots = MultiBuffer::from_array([10, 20, 30, 40], 1);
+    ///
+    /// assert_eq!(snapshots.nth_back(0), &20);
+    /// assert_eq!(snapshots.nth_back(1), &10);
+    /// assert_eq!(snapshots.nth_back(2), &40);
+    /// assert_eq!(snapshots.nth_back(4), &20);
+    /// ```
+    pub fn nth_back(&self, k: usize) -> &T {
+        &self.buffer[(self.index + N - k % N) % N]
+    }
+
+    /// Get readonly copy reference **k** rotation steps ahead of current index
+    ///
+    /// Copy which becomes current after **k** calls to [`MultiBuffer::advance`]. Wraps around
+    /// modulo **N**, so for [DoubleBuffer](crate::DoubleBuffer) `peek_ahead(1)` is the next copy
+    /// and `peek_ahead(2)` is the current one
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::{DoubleBuffer, MultiBuffer};
+    /// let snapshots = MultiBuffer::from_array([10, 20, 30, 40], 2);
+    ///
+    /// assert_eq!(snapshots.peek_ahead(0), &30);
+    /// assert_eq!(snapshots.peek_ahead(1), &40);
+    /// assert_eq!(snapshots.peek_ahead(2), &10);
+    /// assert_eq!(snapshots.peek_ahead(5), &40);
+    ///
+    /// let tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 1);
+    ///
+    /// assert_eq!(tuple.peek_ahead(1), tuple.next());
+    /// assert_eq!(tuple.peek_ahead(2), tuple.current());
+    /// ```
+    pub fn peek_ahead(&self, k: usize) -> &T {
+        &self.buffer[(self.index + k % N) % N]
+    }
+
+    /// Moves current index one step forward, wrapping around modulo **N**
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::MultiBuffer;
+    /// let mut snapshots = MultiBuffer::from_array([10, 20, 30], 0);
+    ///
+    /// snapshots.advance();
+    /// assert_eq!(snapshots.current_index(), 1);
+    ///
+    /// snapshots.advance();
+    /// assert_eq!(snapshots.current_index(), 2);
+    ///
+    /// snapshots.advance();
+    /// assert_eq!(snapshots.current_index(), 0);
+    /// ```
+    pub fn advance(&mut self) {
+        self.index = (self.index + 1) % N;
+        self.generation = self.generation.wrapping_add(1);
+        self.dirty = false;
+        self.pending_swap = false;
+    }
+
+    /// Moves current index **n** steps forward at once, wrapping around modulo **N**
+    ///
+    /// Same as calling [`MultiBuffer::advance`] **n** times, but without looping. For
+    /// [DoubleBuffer](crate::DoubleBuffer) only parity of **n** matters
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    ///
+    /// tuple.rotate_times(3);
+    ///
+    /// assert_eq!(tuple.index(), 1);
+    ///
+    /// tuple.rotate_times(2);
+    ///
+    /// assert_eq!(tuple.index(), 1);
+    /// ```
+    ///
+    /// Rotating zero times does nothing, pending writes stay [dirty](MultiBuffer::is_dirty):
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    ///
+    /// tuple.next_mut().0 = 999;
+    /// tuple.rotate_times(0);
+    ///
+    /// assert!(tuple.is_dirty());
+    /// assert_eq!(tuple.generation(), 0);
+    /// ```
+    pub fn rotate_times(&mut self, n: usize) {
+        if n == 0 {
+            return;
+        }
+        self.index = (self.index + n % N) % N;
+        self.generation = self.generation.wrapping_add(n as u64);
+        self.dirty = false;
+        self.pending_swap = false;
+    }
+
+    /// Iterate over readonly references to copies
+    ///
+    /// Order does **not** depend on current index, same as
+    /// [`DoubleBuffer::split`](crate::DoubleBuffer::split)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([10, 20], 1);
+    ///
+    /// assert_eq!(tuple.iter().sum::<i32>(), 30);
+    /// assert_eq!(tuple.iter().collect::<Vec<_>>(), vec![&10, &20]);
+    /// ```
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.buffer.iter()
+    }
+
+    /// Iterate over mutable references to copies
+    ///
+    /// Order does **not** depend on current index, same as
+    /// [`DoubleBuffer::split_mut`](crate::DoubleBuffer::split_mut)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([10, 20], 1);
+    ///
+    /// for copy in tuple.iter_mut() {
+    ///     *copy += 1;
+    /// }
+    ///
+    /// assert_eq!(tuple.buffer(), &[11, 21]);
+    /// ```
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
+        self.dirty = true;
+        self.buffer.iter_mut()
+    }
+
+    /// Borrow every copy, creating buffer of references with the same current index
+    ///
+    /// Returned buffer borrows from this one, so this buffer can't be mutated while it exists
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 1);
+    ///
+    /// let borrowed: DoubleBuffer<&(i32, i32)> = tuple.as_ref();
+    ///
+    /// assert_eq!(borrowed.current(), &&(20, 10));
+    /// assert_eq!(borrowed.index(), 1);
+    /// ```
+    pub fn as_ref(&self) -> MultiBuffer<&T, N> {
+        let mut copies = self.buffer.iter();
+        self.with_meta_from(core::array::from_fn(|_| copies.next().unwrap()))
+    }
+
+    /// Mutably borrow every copy, creating buffer of references with the same current index
+    ///
+    /// Returned buffer mutably borrows from this one, so this buffer can't be accessed while it
+    /// exists. Index of returned buffer is independent from this one
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 1);
+    ///
+    /// let mut borrowed = tuple.as_mut();
+    /// borrowed.current_mut().0 = 999;
+    /// borrowed.swap();
+    ///
+    /// assert_eq!(tuple.current(), &(999, 10));
+    /// assert_eq!(tuple.index(), 1);
+    /// ```
+    pub fn as_mut(&mut self) -> MultiBuffer<&mut T, N> {
+        self.dirty = true;
+        let meta = self.with_meta_from([(); N]);
+        let mut copies = self.buffer.iter_mut();
+        meta.with_meta_from(core::array::from_fn(|_| copies.next().unwrap()))
+    }
+
+    /// Transforms every copy into another type, preserving current index
+    ///
+    /// Function is applied in order of underlying buffer, **not** starting from current copy
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let tuple = DoubleBuffer::from_buffer([(10, 20), (1, 2)], 1);
+    ///
+    /// let sum = tuple.map(|(a, b)| a + b);
+    ///
+    /// assert_eq!(sum.buffer(), &[30, 3]);
+    /// assert_eq!(sum.index(), 1);
+    /// ```
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> MultiBuffer<U, N> {
+        let meta = self.with_meta_from([(); N]);
+        meta.with_meta_from(self.buffer.map(f))
+    }
+
+    /// Transforms references to every copy into another type, preserving current index
+    ///
+    /// Non-consuming version of [`MultiBuffer::map`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let tuple = DoubleBuffer::from_buffer([(10, 20), (1, 2)], 1);
+    ///
+    /// let sum = tuple.map_ref(|(a, b)| a + b);
+    ///
+    /// assert_eq!(sum.buffer(), &[30, 3]);
+    /// assert_eq!(sum.index(), 1);
+    /// assert_eq!(tuple.current(), &(1, 2));
+    /// ```
+    pub fn map_ref<U>(&self, mut f: impl FnMut(&T) -> U) -> MultiBuffer<U, N> {
+        self.with_meta_from(core::array::from_fn(|i| f(&self.buffer[i])))
+    }
+
+    /// Build buffer of other copies carrying index, generation, swap counter, dirty flag and
+    /// slot labels of this one
+    ///
+    /// Every buffer derived from existing one is built here, so metadata is copied in one place.
+    /// Pending swap belongs to the original buffer and is not carried over
+    pub(crate) fn with_meta_from<U>(&self, buffer: [U; N]) -> MultiBuffer<U, N> {
+        MultiBuffer {
+            buffer,
+            index: self.index,
+            generation: self.generation,
+            swap_baseline: self.swap_baseline,
+            dirty: self.dirty,
+            pending_swap: false,
+            labels: self.labels,
+        }
+    }
+
+    /// Panics with readable message instead of out of bounds access if index was set too high
+    pub(crate) fn debug_check_index(&self) {
+        if N == 2 {
+            debug_assert!(self.index < N, "{}", IndexOutOfRange(self.index));
+        } else {
+            debug_assert!(
+                self.index < N,
+                "MultiBuffer index must be less than {}, got {}",
+                N,
+                self.index
+            );
+        }
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for MultiBuffer<T, N>
+where
+    T: fmt::Debug,
+{
+    /// Format buffer labeling current copy
+    ///
+    /// [DoubleBuffer](crate::DoubleBuffer) is formatted as
+    /// `DoubleBuffer { current: .., next: .., index: .. }`, other buffers are formatted as
+    /// `MultiBuffer { current: .., buffer: .., index: .. }`. Custom
+    /// [slot labels](crate::DoubleBuffer::set_slot_labels) are appended as `slot: ..`
+    ///
+    /// Never panics: copies missing because of index out of range, e.g. after
+    /// [`DoubleBuffer::set_index`](crate::DoubleBuffer::set_index), are shown as `None`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::{DoubleBuffer, MultiBuffer};
+    /// let tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 1);
+    ///
+    /// assert_eq!(
+    ///     format!("{:?}", tuple),
+    ///     "DoubleBuffer { current: (20, 10), next: (10, 20), index: 1 }"
+    /// );
+    ///
+    /// let snapshots = MultiBuffer::from_array([10, 20, 30], 1);
+    ///
+    /// assert_eq!(
+    ///     format!("{:?}", snapshots),
+    ///     "MultiBuffer { current: 20, buffer: [10, 20, 30], index: 1 }"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let current = self.buffer.get(self.index);
+        let slot = self
+            .labels
+            .as_ref()
+            .and_then(|labels| labels.get(self.index));
+        if N == 2 {
+            let next = current.and_then(|_| self.buffer.get(1 - self.index));
+            let mut debug = f.debug_struct("DoubleBuffer");
+            debug
+                .field("current", or_none(current))
+                .field("next", or_none(next))
+                .field("index", &self.index);
+            if self.labels.is_some() {
+                debug.field("slot", or_none(slot));
+            }
+            debug.finish()
+        } else {
+            let mut debug = f.debug_struct("MultiBuffer");
+            debug
+                .field("current", or_none(current))
+                .field("buffer", &self.buffer)
+                .field("index", &self.index);
+            if self.labels.is_some() {
+                debug.field("slot", or_none(slot));
+            }
+            debug.finish()
+        }
+    }
+}
+
+/// Format missing copy or label as `None`, so buffer with index out of range can still be printed
+fn or_none<T>(value: Option<&T>) -> &dyn fmt::Debug
+where
+    T: fmt::Debug,
+{
+    match value {
+        Some(value) => value,
+        None => &None::<()>,
+    }
+}
+
+impl<T, const N: usize> Clone for MultiBuffer<T, N>
+where
+    T: Clone,
+{
+    /// Clone every copy and current index
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 1);
+    /// let snapshot = tuple.clone();
+    ///
+    /// tuple.current_mut().0 = 999;
+    /// tuple.swap();
+    ///
+    /// assert_eq!(snapshot.buffer(), &[(10, 20), (20, 10)]);
+    /// assert_eq!(snapshot.index(), 1);
+    /// assert_eq!(tuple.buffer(), &[(10, 20), (999, 10)]);
+    /// assert_eq!(tuple.index(), 0);
+    /// ```
+    fn clone(&self) -> Self {
+        let mut clone = self.with_meta_from(self.buffer.clone());
+        clone.pending_swap = self.pending_swap;
+        clone
+    }
+}
+
+impl<T, const N: usize> PartialEq for MultiBuffer<T, N>
+where
+    T: PartialEq,
+{
+    /// Compare every copy and current index
+    ///
+    /// [Generation](MultiBuffer::generation) and [dirty flag](MultiBuffer::is_dirty) are ignored,
+    /// buffers with different history are equal as long as their contents are
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    ///
+    /// assert_eq!(tuple, DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0));
+    /// assert_ne!(tuple, DoubleBuffer::from_buffer([(10, 20), (20, 10)], 1));
+    /// assert_ne!(tuple, DoubleBuffer::from_buffer([(10, 20), (10, 20)], 0));
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index && self.buffer == other.buffer
+    }
+}
+
+impl<T, const N: usize> Eq for MultiBuffer<T, N> where T: Eq {}
+
+impl<T, const N: usize> Hash for MultiBuffer<T, N>
+where
+    T: Hash,
+{
+    /// Hash every copy and current index, consistently with [PartialEq]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// use std::collections::HashSet;
+    ///
+    /// let mut set = HashSet::new();
+    ///
+    /// set.insert(DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0));
+    /// set.insert(DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0));
+    ///
+    /// assert_eq!(set.len(), 1);
+    ///
+    /// set.insert(DoubleBuffer::from_buffer([(10, 20), (20, 10)], 1));
+    ///
+    /// assert_eq!(set.len(), 2);
+    /// ```
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.buffer.hash(state);
+        self.index.hash(state);
+    }
+}
+
+impl<T, const N: usize> Deref for MultiBuffer<T, N> {
+    type Target = T;
+
+    /// Dereference to current copy
+    ///
+    /// Always targets the copy under current index, **never** the next one
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    ///
+    /// assert_eq!(tuple.0, 10);
+    ///
+    /// tuple.swap();
+    ///
+    /// assert_eq!(tuple.0, 20);
+    ///
+    /// let names = DoubleBuffer::from_buffer([String::from("first"), String::from("second")], 1);
+    ///
+    /// assert_eq!(names.len(), 6);
+    /// ```
+    fn deref(&self) -> &Self::Target {
+        self.current()
+    }
+}
+
+impl<T, const N: usize> DerefMut for MultiBuffer<T, N> {
+    /// Mutably dereference to current copy
+    ///
+    /// Always targets the copy under current index, **never** the next one
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    ///
+    /// tuple.0 = 999;
+    ///
+    /// assert_eq!(tuple.current(), &(999, 20));
+    /// assert_eq!(tuple.next(), &(20, 10));
+    ///
+    /// let mut names = DoubleBuffer::new(String::from("first"));
+    ///
+    /// names.push_str(" and only");
+    ///
+    /// assert_eq!(names.current(), "first and only");
+    /// assert_eq!(names.next(), "first");
+    /// ```
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.current_mut()
+    }
+}
+
+impl<T, const N: usize> AsRef<T> for MultiBuffer<T, N> {
+    /// Reference current copy, same as [Deref]
+    ///
+    /// Method syntax resolves to inherent [`MultiBuffer::as_ref`] instead, this impl is meant for
+    /// generic code bound by [AsRef]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    ///
+    /// fn shout(text: impl AsRef<String>) -> String {
+    ///     text.as_ref().to_uppercase()
+    /// }
+    ///
+    /// let names = DoubleBuffer::new_with(String::from("first"), String::from("second"));
+    ///
+    /// assert_eq!(shout(&names), "FIRST");
+    /// ```
+    fn as_ref(&self) -> &T {
+        self.current()
+    }
+}
+
+impl<T, const N: usize> AsMut<T> for MultiBuffer<T, N> {
+    /// Mutably reference current copy, same as [DerefMut]
+    ///
+    /// Method syntax resolves to inherent [`MultiBuffer::as_mut`] instead, this impl is meant for
+    /// generic code bound by [AsMut]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    ///
+    /// fn append(mut text: impl AsMut<String>) {
+    ///     text.as_mut().push('!');
+    /// }
+    ///
+    /// let mut names = DoubleBuffer::new_with(String::from("first"), String::from("second"));
+    ///
+    /// append(&mut names);
+    ///
+    /// assert_eq!(names.current(), "first!");
+    /// assert_eq!(names.next(), "second");
+    /// ```
+    fn as_mut(&mut self) -> &mut T {
+        self.current_mut()
+    }
+}
+
+impl<T, const N: usize> Index<usize> for MultiBuffer<T, N> {
+    type Output = T;
+
+    /// Access copy by its position in underlying buffer
+    ///
+    /// Order does **not** depend on current index, same as
+    /// [`DoubleBuffer::split`](crate::DoubleBuffer::split)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    ///
+    /// assert_eq!(tuple[0], (10, 20));
+    /// assert_eq!(tuple[1], (20, 10));
+    ///
+    /// tuple.swap();
+    ///
+    /// // Same as before!
+    /// assert_eq!(tuple[0], (10, 20));
+    /// assert_eq!(tuple[1], (20, 10));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if slot is not less than **N**
+    ///
+    /// ```should_panic
+    /// use bevy_double_res::DoubleBuffer;
+    /// let tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    ///
+    /// let _ = tuple[2];
+    /// ```
+    fn index(&self, slot: usize) -> &Self::Output {
+        assert!(slot < N, "slot must be less than {}, got {}", N, slot);
+        &self.buffer[slot]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for MultiBuffer<T, N> {
+    /// Mutably access copy by its position in underlying buffer
+    ///
+    /// Order does **not** depend on current index, same as
+    /// [`DoubleBuffer::split_mut`](crate::DoubleBuffer::split_mut)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 1);
+    ///
+    /// tuple[0].0 = 999;
+    ///
+    /// assert_eq!(tuple.next(), &(999, 20));
+    /// assert_eq!(tuple.current(), &(20, 10));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if slot is not less than **N**
+    fn index_mut(&mut self, slot: usize) -> &mut Self::Output {
+        assert!(slot < N, "slot must be less than {}, got {}", N, slot);
+        self.dirty = true;
+        &mut self.buffer[slot]
+    }
+}
+
+impl<T, const N: usize> IntoIterator for MultiBuffer<T, N> {
+    type Item = T;
+    type IntoIter = core::array::IntoIter<T, N>;
+
+    /// Consume buffer, iterating over owned copies
+    ///
+    /// Order does **not** depend on current index, same as [`MultiBuffer::iter`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let tuple = DoubleBuffer::from_buffer([String::from("first"), String::from("second")], 1);
+    ///
+    /// assert_eq!(tuple.into_iter().collect::<Vec<_>>(), vec!["first", "second"]);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.buffer.into_iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a MultiBuffer<T, N> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    /// Same as [`MultiBuffer::iter`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let tuple = DoubleBuffer::from_buffer([10, 20], 1);
+    ///
+    /// let mut sum = 0;
+    /// for copy in &tuple {
+    ///     sum += copy;
+    /// }
+    ///
+    /// assert_eq!(sum, 30);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut MultiBuffer<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+
+    /// Same as [`MultiBuffer::iter_mut`]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// Buffer of any thread-safe **T** is a resource, so **T** itself does not need to derive
+/// [Resource](bevy_ecs::system::Resource)
+///
+/// # Example
+///
+/// ```
+/// use bevy::ecs::schedule::Stage;
+/// use bevy::prelude::*;
+/// use bevy_double_res::prelude::*;
+///
+/// #[derive(Clone)]
+/// struct MyColors(Color, Color);
+///
+/// fn setup(mut commands: Commands) {
+///     commands.insert_resource(MyColors(Color::RED, Color::BLUE).into_double_buf());
+/// }
+///
+/// fn circular_dependent_system(mut colors: DoubleResMut<MyColors>) {
+///     colors.apply(|current, next| {
+///         next.0 = current.1;
+///         next.1 = current.0;
+///     });
+///     colors.swap();
+/// }
+///
+/// let mut world = World::new();
+///
+/// SystemStage::single_threaded().with_system(setup).run(&mut world);
+/// SystemStage::single_threaded()
+///     .with_system(circular_dependent_system)
+///     .run(&mut world);
+///
+/// assert_eq!(world.resource::<DoubleBuffer<MyColors>>().current().0, Color::BLUE);
+/// ```
+#[cfg(feature = "bevy")]
+impl<T, const N: usize> bevy_ecs::system::Resource for MultiBuffer<T, N> where
+    T: Send + Sync + 'static
+{
+}
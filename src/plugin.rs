@@ -0,0 +1,391 @@
+//! Provides plugin and [App] extension swapping buffer resources automatically
+
+use crate::systems::swap_double_buffer;
+use crate::{DoubleBuffer, DoubleResMut};
+use bevy_app::{App, CoreStage, Plugin};
+use bevy_ecs::schedule::{IntoSystemDescriptor, StageLabel, StageLabelId, SystemLabel};
+use bevy_ecs::system::{ResMut, Resource};
+use std::marker::PhantomData;
+
+/// Label shared by all swap systems registered by this module
+///
+/// Order other systems relative to it to run them before or after every swap at once
+#[derive(SystemLabel, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DoubleBufferSwapSet;
+
+/// Labels ordering writers, swap and readers of buffer within [CoreStage::Update]
+///
+/// Configured by [`DoubleBufferAppExt::configure_double_buffer_stages`]: systems labeled
+/// [`DoubleBufferStage::Write`] run before swap, systems labeled [`DoubleBufferStage::Read`] run
+/// after it
+#[derive(SystemLabel, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DoubleBufferStage {
+    /// Systems writing next copy
+    Write,
+    /// Swap of buffers
+    Swap,
+    /// Systems reading committed copy
+    Read,
+}
+
+/// Plugin swapping [DoubleBuffer<T>](crate::DoubleBuffer) resource once per frame
+///
+/// Swap runs in [CoreStage::Last] unless configured otherwise with
+/// [`DoubleBufferPlugin::swap_in`], and every frame unless configured otherwise with
+/// [`DoubleBufferPlugin::swap_every`]
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_double_res::prelude::*;
+///
+/// let mut app = App::new();
+/// app.insert_resource(DoubleBuffer::from_buffer([10, 20], 0))
+///     .add_plugin(DoubleBufferPlugin::<i32>::default());
+///
+/// app.update();
+///
+/// assert_eq!(app.world.resource::<DoubleBuffer<i32>>().index(), 1);
+///
+/// app.update();
+///
+/// assert_eq!(app.world.resource::<DoubleBuffer<i32>>().index(), 0);
+/// ```
+///
+/// Resource itself must be inserted separately. Nothing is added if swap system of the same
+/// buffer was already registered with the same settings, e.g. by
+/// [`DoubleBufferAppExt::init_double_resource`]
+///
+/// # Panics
+///
+/// Panics on build if swap system of the same buffer was already registered with different
+/// settings, since only one of them could take effect
+///
+/// ```should_panic
+/// use bevy::prelude::*;
+/// use bevy_double_res::prelude::*;
+///
+/// App::new()
+///     .init_double_resource::<i32>()
+///     .add_plugin(DoubleBufferPlugin::<i32>::default().swap_every(2));
+/// ```
+pub struct DoubleBufferPlugin<T> {
+    stage: StageLabelId,
+    every: u32,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> DoubleBufferPlugin<T> {
+    /// Choose stage where swap runs
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_double_res::prelude::*;
+    ///
+    /// let mut app = App::new();
+    /// app.insert_resource(DoubleBuffer::from_buffer([10, 20], 0))
+    ///     .add_plugin(DoubleBufferPlugin::<i32>::default().swap_in(CoreStage::First));
+    ///
+    /// app.update();
+    ///
+    /// assert_eq!(app.world.resource::<DoubleBuffer<i32>>().current(), &20);
+    /// ```
+    pub fn swap_in(mut self, stage: impl StageLabel) -> Self {
+        self.stage = stage.as_label();
+        self
+    }
+
+    /// Swap only once per **n** frames, starting from the first one
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_double_res::prelude::*;
+    ///
+    /// let mut app = App::new();
+    /// app.insert_resource(DoubleBuffer::from_buffer([10, 20], 0))
+    ///     .add_plugin(DoubleBufferPlugin::<i32>::default().swap_every(2));
+    ///
+    /// for _ in 0..4 {
+    ///     app.update();
+    /// }
+    ///
+    /// assert_eq!(app.world.resource::<DoubleBuffer<i32>>().generation(), 2);
+    /// assert_eq!(app.world.resource::<DoubleBuffer<i32>>().index(), 0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if **n** is zero
+    pub fn swap_every(mut self, n: u32) -> Self {
+        assert!(n > 0, "swap period must be positive");
+        self.every = n;
+        self
+    }
+}
+
+impl<T> Default for DoubleBufferPlugin<T> {
+    /// Create plugin swapping in [CoreStage::Last]
+    fn default() -> Self {
+        Self {
+            stage: CoreStage::Last.as_label(),
+            every: 1,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Plugin for DoubleBufferPlugin<T>
+where
+    T: Send + Sync + 'static,
+{
+    fn build(&self, app: &mut App) {
+        let settings = SwapSettings {
+            stage: self.stage,
+            every: self.every,
+            ordered: false,
+        };
+        if !register_swap::<T>(app, settings) {
+            return;
+        }
+        if self.every == 1 {
+            app.add_system_to_stage(
+                self.stage,
+                swap_double_buffer::<T>.label(DoubleBufferSwapSet),
+            );
+        } else {
+            app.insert_resource(SwapPeriod::<T> {
+                every: self.every,
+                frame: 0,
+                marker: PhantomData,
+            })
+            .add_system_to_stage(
+                self.stage,
+                swap_double_buffer_periodic::<T>.label(DoubleBufferSwapSet),
+            );
+        }
+    }
+}
+
+/// Frame counter of [`DoubleBufferPlugin::swap_every`]
+#[derive(Resource)]
+struct SwapPeriod<T> {
+    every: u32,
+    frame: u32,
+    marker: PhantomData<fn() -> T>,
+}
+
+fn swap_double_buffer_periodic<T>(mut buffer: DoubleResMut<T>, mut period: ResMut<SwapPeriod<T>>)
+where
+    T: Send + Sync + 'static,
+{
+    if period.frame == 0 {
+        buffer.swap();
+    }
+    period.frame = (period.frame + 1) % period.every;
+}
+
+/// Where and how often swap system of a buffer runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SwapSettings {
+    stage: StageLabelId,
+    every: u32,
+    ordered: bool,
+}
+
+/// Marks swap system of [DoubleBuffer<T>] as added to the app with given settings
+#[derive(Resource)]
+struct SwapRegistered<T> {
+    settings: SwapSettings,
+    marker: PhantomData<fn() -> T>,
+}
+
+/// Mark swap system of [DoubleBuffer<T>] as added, returns **false** if it already was with the
+/// same settings
+///
+/// Panics if it was added with different settings, since systems can't be removed and keeping
+/// either of them would silently drop the other configuration
+fn register_swap<T>(app: &mut App, settings: SwapSettings) -> bool
+where
+    T: Send + Sync + 'static,
+{
+    if let Some(registered) = app.world.get_resource::<SwapRegistered<T>>() {
+        assert!(
+            registered.settings == settings,
+            "swap system of DoubleBuffer<{}> is already registered with {:?}, can't register it \
+             again with {:?}",
+            std::any::type_name::<T>(),
+            registered.settings,
+            settings,
+        );
+        return false;
+    }
+    app.insert_resource(SwapRegistered::<T> {
+        settings,
+        marker: PhantomData,
+    });
+    true
+}
+
+/// Extends [App] with double buffer resource registration
+pub trait DoubleBufferAppExt {
+    /// Insert default [DoubleBuffer<T>] resource and swap it in [CoreStage::Last] under
+    /// [DoubleBufferSwapSet] label
+    ///
+    /// Same as [`App::init_resource`], already inserted resource is kept. Swap system is not added
+    /// if one was already registered for the same buffer with the same settings, e.g. by default
+    /// [DoubleBufferPlugin]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_double_res::prelude::*;
+    ///
+    /// fn check_system(numbers: DoubleRes<i32>, flags: DoubleRes<bool>) {
+    ///     assert_eq!(numbers.index(), 0);
+    ///     assert_eq!(flags.index(), 0);
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.init_double_resource::<i32>()
+    ///     .init_double_resource::<bool>()
+    ///     .add_system_to_stage(CoreStage::Last, check_system.before(DoubleBufferSwapSet));
+    ///
+    /// app.update();
+    ///
+    /// assert_eq!(app.world.resource::<DoubleBuffer<i32>>().index(), 1);
+    /// assert_eq!(app.world.resource::<DoubleBuffer<bool>>().index(), 1);
+    /// ```
+    ///
+    /// Resource inserted before is kept and swapped once per frame:
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_double_res::prelude::*;
+    ///
+    /// let mut app = App::new();
+    /// app.insert_resource(DoubleBuffer::from_buffer([10, 20], 0))
+    ///     .add_plugin(DoubleBufferPlugin::<i32>::default())
+    ///     .init_double_resource::<i32>();
+    ///
+    /// app.update();
+    ///
+    /// assert_eq!(app.world.resource::<DoubleBuffer<i32>>().current(), &20);
+    /// assert_eq!(app.world.resource::<DoubleBuffer<i32>>().generation(), 1);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if swap system of the same buffer was already registered with different settings,
+    /// since only one of them could take effect
+    ///
+    /// ```should_panic
+    /// use bevy::prelude::*;
+    /// use bevy_double_res::prelude::*;
+    ///
+    /// App::new()
+    ///     .init_double_resource::<i32>()
+    ///     .configure_double_buffer_stages::<i32>();
+    /// ```
+    fn init_double_resource<T>(&mut self) -> &mut Self
+    where
+        T: Default + Send + Sync + 'static;
+
+    /// Swap [DoubleBuffer<T>] resource in [CoreStage::Update] under [`DoubleBufferStage::Swap`]
+    /// label, after [`DoubleBufferStage::Write`] and before [`DoubleBufferStage::Read`]
+    ///
+    /// Resource itself must be inserted separately. Swap system is not added if one was already
+    /// registered for the same buffer with the same settings
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_double_res::prelude::*;
+    ///
+    /// fn write_system(mut numbers: DoubleResMut<i32>) {
+    ///     numbers.apply(|current, next| *next = current + 1);
+    /// }
+    ///
+    /// fn read_system(numbers: DoubleRes<i32>) {
+    ///     assert_eq!(numbers.current(), &11);
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.insert_resource(DoubleBuffer::new(10))
+    ///     .configure_double_buffer_stages::<i32>()
+    ///     .add_system(read_system.label(DoubleBufferStage::Read))
+    ///     .add_system(write_system.label(DoubleBufferStage::Write));
+    ///
+    /// app.update();
+    ///
+    /// assert_eq!(app.world.resource::<DoubleBuffer<i32>>().current(), &11);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if swap system of the same buffer was already registered with different settings,
+    /// e.g. by [`DoubleBufferAppExt::init_double_resource`] swapping in [CoreStage::Last]
+    ///
+    /// ```should_panic
+    /// use bevy::prelude::*;
+    /// use bevy_double_res::prelude::*;
+    ///
+    /// App::new()
+    ///     .add_plugin(DoubleBufferPlugin::<i32>::default())
+    ///     .configure_double_buffer_stages::<i32>();
+    /// ```
+    fn configure_double_buffer_stages<T>(&mut self) -> &mut Self
+    where
+        T: Send + Sync + 'static;
+}
+
+impl DoubleBufferAppExt for App {
+    fn init_double_resource<T>(&mut self) -> &mut Self
+    where
+        T: Default + Send + Sync + 'static,
+    {
+        if !self.world.contains_resource::<DoubleBuffer<T>>() {
+            self.insert_resource(DoubleBuffer::<T>::default());
+        }
+        let settings = SwapSettings {
+            stage: CoreStage::Last.as_label(),
+            every: 1,
+            ordered: false,
+        };
+        if register_swap::<T>(self, settings) {
+            self.add_system_to_stage(
+                CoreStage::Last,
+                swap_double_buffer::<T>.label(DoubleBufferSwapSet),
+            );
+        }
+        self
+    }
+
+    fn configure_double_buffer_stages<T>(&mut self) -> &mut Self
+    where
+        T: Send + Sync + 'static,
+    {
+        let settings = SwapSettings {
+            stage: CoreStage::Update.as_label(),
+            every: 1,
+            ordered: true,
+        };
+        if register_swap::<T>(self, settings) {
+            self.add_system_to_stage(
+                CoreStage::Update,
+                swap_double_buffer::<T>
+                    .label(DoubleBufferStage::Swap)
+                    .label(DoubleBufferSwapSet)
+                    .after(DoubleBufferStage::Write)
+                    .before(DoubleBufferStage::Read),
+            );
+        }
+        self
+    }
+}
@@ -0,0 +1,108 @@
+//! Provides extension traits forwarding buffer methods to resource guards
+//!
+//! These are purely convenience wrappers: every method behaves exactly like the one of
+//! [DoubleBuffer] it forwards to
+
+use crate::{DoubleBuffer, DoubleRes, DoubleResMut};
+
+/// Forwards readonly [DoubleBuffer] methods to [DoubleRes] and [DoubleResMut]
+///
+/// # Example
+///
+/// ```
+/// use bevy::ecs::schedule::Stage;
+/// use bevy::prelude::*;
+/// use bevy_double_res::prelude::*;
+///
+/// fn display_system(tuple: DoubleRes<(i32, i32)>) {
+///     assert_eq!(tuple.current(), &(10, 20));
+///     assert_eq!(tuple.next(), &(20, 10));
+/// }
+///
+/// let mut world = World::new();
+/// world.insert_resource(DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0));
+///
+/// SystemStage::single_threaded()
+///     .with_system(display_system)
+///     .run(&mut world);
+/// ```
+pub trait DoubleResExt<T> {
+    /// Same as [`DoubleBuffer::current`]
+    fn current(&self) -> &T;
+
+    /// Same as [`DoubleBuffer::next`]
+    fn next(&self) -> &T;
+}
+
+/// Forwards mutable [DoubleBuffer] methods to [DoubleResMut]
+///
+/// # Example
+///
+/// ```
+/// use bevy::ecs::schedule::Stage;
+/// use bevy::prelude::*;
+/// use bevy_double_res::prelude::*;
+///
+/// fn circular_dependent_system(mut tuple: DoubleResMut<(i32, i32)>) {
+///     tuple.apply(|current, next| {
+///         next.0 = current.1;
+///         next.1 = current.0;
+///     });
+///     tuple.swap();
+/// }
+///
+/// let mut world = World::new();
+/// world.insert_resource((10, 20).into_double_buf());
+///
+/// SystemStage::single_threaded()
+///     .with_system(circular_dependent_system)
+///     .run(&mut world);
+///
+/// assert_eq!(world.resource::<DoubleBuffer<(i32, i32)>>().current(), &(20, 10));
+/// ```
+pub trait DoubleResMutExt<T>: DoubleResExt<T> {
+    /// Same as [`DoubleBuffer::apply`]
+    fn apply<Res>(&mut self, f: impl FnOnce(&T, &mut T) -> Res) -> Res;
+
+    /// Same as [`DoubleBuffer::swap`]
+    fn swap(&mut self);
+}
+
+impl<'w, T> DoubleResExt<T> for DoubleRes<'w, T>
+where
+    T: Send + Sync + 'static,
+{
+    fn current(&self) -> &T {
+        DoubleBuffer::current(self)
+    }
+
+    fn next(&self) -> &T {
+        DoubleBuffer::next(self)
+    }
+}
+
+impl<'w, T> DoubleResExt<T> for DoubleResMut<'w, T>
+where
+    T: Send + Sync + 'static,
+{
+    fn current(&self) -> &T {
+        DoubleBuffer::current(self)
+    }
+
+    fn next(&self) -> &T {
+        DoubleBuffer::next(self)
+    }
+}
+
+impl<'w, T> DoubleResMutExt<T> for DoubleResMut<'w, T>
+where
+    T: Send + Sync + 'static,
+{
+    fn apply<Res>(&mut self, f: impl FnOnce(&T, &mut T) -> Res) -> Res {
+        DoubleBuffer::apply(self, f)
+    }
+
+    fn swap(&mut self) {
+        DoubleBuffer::swap(self)
+    }
+}
@@ -0,0 +1,121 @@
+//! Provides [serde](https://serde.rs/) support for buffers behind the **serde** feature
+//!
+//! Buffer is serialized as a struct of all copies and current index, so a round-trip restores
+//! the exact state
+//!
+//! # Example
+//!
+//! ```
+//! use bevy_double_res::DoubleBuffer;
+//! let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+//! tuple.swap();
+//!
+//! let json = serde_json::to_string(&tuple).unwrap();
+//! let restored: DoubleBuffer<(u8, u8)> = serde_json::from_str(&json).unwrap();
+//!
+//! assert_eq!(restored.current(), tuple.current());
+//! assert_eq!(restored.next(), tuple.next());
+//! assert_eq!(restored.index(), tuple.index());
+//! ```
+
+use crate::MultiBuffer;
+use core::fmt;
+use core::marker::PhantomData;
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Unexpected, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+/// Serialized form of [MultiBuffer], generic over the representation of copies
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "MultiBuffer")]
+struct Repr<C> {
+    buffer: C,
+    index: usize,
+}
+
+/// Fixed-size sequence of copies, deserialized without allocation
+struct Copies<T, const N: usize>([T; N]);
+
+struct CopiesVisitor<T, const N: usize>(PhantomData<T>);
+
+impl<T, const N: usize> Serialize for MultiBuffer<T, N>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Repr {
+            buffer: &self.buffer[..],
+            index: self.index,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T, const N: usize> Deserialize<'de> for MultiBuffer<T, N>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let Repr {
+            buffer: Copies(buffer),
+            index,
+        } = Repr::deserialize(deserializer)?;
+
+        if index >= N {
+            return Err(de::Error::invalid_value(
+                Unexpected::Unsigned(index as u64),
+                &"index of an existing copy",
+            ));
+        }
+
+        Ok(Self::from_array(buffer, index))
+    }
+}
+
+impl<'de, T, const N: usize> Deserialize<'de> for Copies<T, N>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(CopiesVisitor(PhantomData))
+    }
+}
+
+impl<'de, T, const N: usize> Visitor<'de> for CopiesVisitor<T, N>
+where
+    T: Deserialize<'de>,
+{
+    type Value = Copies<T, N>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a sequence of {} copies", N)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut copies: [Option<T>; N] = core::array::from_fn(|_| None);
+
+        for (i, copy) in copies.iter_mut().enumerate() {
+            *copy = Some(
+                seq.next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(i, &self))?,
+            );
+        }
+
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(N + 1, &self));
+        }
+
+        Ok(Copies(copies.map(Option::unwrap)))
+    }
+}
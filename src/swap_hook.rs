@@ -0,0 +1,183 @@
+//! Provides double buffer deriving next copy from current one on every swap
+//!
+//! Hook is boxed outside of the buffer, so [DoubleBuffer] itself stays covariant over **T**
+
+use crate::DoubleBuffer;
+use core::fmt;
+use core::ops::Deref;
+
+/// Wrapper of [DoubleBuffer] which calls a closure after every swap
+///
+/// Hook receives new current copy for reading and new next copy for writing, so next copy is
+/// always derived from current one. Writes made by hook don't mark buffer as
+/// [dirty](crate::MultiBuffer::is_dirty)
+///
+/// Wrapped buffer is only reachable readonly, so index can't be flipped behind hook's back.
+/// [`DoubleBufferWithSwapHook::swap`], [`DoubleBufferWithSwapHook::swap_if`] and
+/// [`DoubleBufferWithSwapHook::apply_swap`] are the only flipping methods and all of them run it
+///
+/// # Example
+///
+/// ```
+/// use bevy_double_res::DoubleBuffer;
+/// let mut tuple = DoubleBuffer::from_buffer([0, 0], 0)
+///     .with_swap_hook(|current: &i32, next: &mut i32| *next = *current);
+///
+/// tuple.apply(|_, next| *next = 10);
+/// tuple.swap();
+///
+/// assert_eq!(tuple.current(), &10);
+/// assert_eq!(tuple.next(), &10);
+///
+/// tuple.apply(|_, next| *next += 5);
+/// tuple.swap();
+///
+/// assert_eq!(tuple.current(), &15);
+/// assert_eq!(tuple.next(), &15);
+/// ```
+///
+/// Flipping methods of the wrapped buffer, e.g. [`DoubleBuffer::swap_to`], can't be reached:
+///
+/// ```compile_fail
+/// use bevy_double_res::DoubleBuffer;
+/// let mut tuple = DoubleBuffer::new(0).with_swap_hook(|current, next| *next = *current);
+///
+/// tuple.swap_to(1);
+/// ```
+pub struct DoubleBufferWithSwapHook<T> {
+    buffer: DoubleBuffer<T>,
+    hook: Box<SwapHook<T>>,
+}
+
+/// Closure called by [DoubleBufferWithSwapHook] with new current and next copies
+type SwapHook<T> = dyn Fn(&T, &mut T) + Send + Sync;
+
+impl<T> DoubleBufferWithSwapHook<T> {
+    /// Wrap buffer with closure called after every swap
+    ///
+    /// Hook isn't called on wrapping, so next copy is kept as is until the first swap
+    pub fn new(buffer: DoubleBuffer<T>, hook: impl Fn(&T, &mut T) + Send + Sync + 'static) -> Self {
+        Self {
+            buffer,
+            hook: Box::new(hook),
+        }
+    }
+
+    /// Same as [`DoubleBuffer::apply`]
+    pub fn apply<Res>(&mut self, f: impl FnOnce(&T, &mut T) -> Res) -> Res {
+        self.buffer.apply(f)
+    }
+
+    /// Swap the buffer, then let hook derive new next copy from new current one
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let step = 5;
+    /// let mut counter = DoubleBuffer::new(0).with_swap_hook(move |current, next| {
+    ///     *next = current + step;
+    /// });
+    ///
+    /// counter.swap();
+    ///
+    /// assert_eq!(counter.current(), &0);
+    /// assert_eq!(counter.next(), &5);
+    /// assert_eq!(counter.generation(), 1);
+    /// assert!(!counter.is_dirty());
+    /// ```
+    pub fn swap(&mut self) {
+        self.buffer.swap();
+        let [first, second] = &mut self.buffer.buffer;
+        match self.buffer.index {
+            0 => (self.hook)(first, second),
+            _ => (self.hook)(second, first),
+        }
+    }
+
+    /// Same as [`DoubleBuffer::swap_if`], hook runs only if swap happened
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut tuple = DoubleBuffer::from_buffer([1, 2], 0).with_swap_hook(|current, next| {
+    ///     *next = current * 10;
+    /// });
+    ///
+    /// assert!(!tuple.swap_if(false));
+    /// assert_eq!(tuple.buffer(), &[1, 2]);
+    ///
+    /// assert!(tuple.swap_if(true));
+    /// assert_eq!(tuple.current(), &2);
+    /// assert_eq!(tuple.next(), &20);
+    /// ```
+    pub fn swap_if(&mut self, condition: bool) -> bool {
+        if condition {
+            self.swap();
+        }
+        condition
+    }
+
+    /// Same as [`DoubleBuffer::apply_swap`], hook runs after the swap
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_double_res::DoubleBuffer;
+    /// let mut history = DoubleBuffer::new(vec![1]).with_swap_hook(|current, next| {
+    ///     next.clone_from(current);
+    /// });
+    ///
+    /// let len = history.apply_swap(|current, next| {
+    ///     next.push(current.len() + 1);
+    ///     next.len()
+    /// });
+    ///
+    /// assert_eq!(len, 2);
+    /// assert_eq!(history.current(), &[1, 2]);
+    /// assert_eq!(history.next(), &[1, 2]);
+    /// ```
+    pub fn apply_swap<Res>(&mut self, f: impl FnOnce(&T, &mut T) -> Res) -> Res {
+        let result = self.apply(f);
+        self.swap();
+        result
+    }
+
+    /// Unwrap buffer, dropping the hook
+    pub fn into_inner(self) -> DoubleBuffer<T> {
+        self.buffer
+    }
+}
+
+impl<T> DoubleBuffer<T> {
+    /// Wrap buffer with closure called after every swap
+    ///
+    /// Same as [`DoubleBufferWithSwapHook::new`]
+    pub fn with_swap_hook(
+        self,
+        hook: impl Fn(&T, &mut T) + Send + Sync + 'static,
+    ) -> DoubleBufferWithSwapHook<T> {
+        DoubleBufferWithSwapHook::new(self, hook)
+    }
+}
+
+impl<T> Deref for DoubleBufferWithSwapHook<T> {
+    type Target = DoubleBuffer<T>;
+
+    /// Dereference to wrapped buffer
+    ///
+    /// Only readonly access is provided, so swaps can't bypass the hook
+    fn deref(&self) -> &Self::Target {
+        &self.buffer
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for DoubleBufferWithSwapHook<T> {
+    /// Format wrapped buffer, hook itself is opaque
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DoubleBufferWithSwapHook")
+            .field("buffer", &self.buffer)
+            .finish_non_exhaustive()
+    }
+}
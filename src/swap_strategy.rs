@@ -0,0 +1,101 @@
+//! Provides pluggable swap policies for [DoubleBuffer]
+
+use crate::DoubleBuffer;
+
+/// Policy of committing next copy, used by [`DoubleBuffer::advance_with`]
+///
+/// # Example
+///
+/// ```
+/// use bevy_double_res::{DoubleBuffer, SwapStrategy};
+///
+/// struct Never;
+///
+/// impl<T> SwapStrategy<T> for Never {
+///     fn apply(&self, _buffer: &mut DoubleBuffer<T>) {}
+/// }
+///
+/// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+///
+/// tuple.advance_with(&Never);
+///
+/// assert_eq!(tuple.current(), &(10, 20));
+/// ```
+pub trait SwapStrategy<T> {
+    /// Commit next copy of **buffer**
+    fn apply(&self, buffer: &mut DoubleBuffer<T>);
+}
+
+/// Flips index, same as [`DoubleBuffer::swap`]
+///
+/// # Example
+///
+/// ```
+/// use bevy_double_res::{DoubleBuffer, FlipIndex};
+/// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+///
+/// tuple.advance_with(&FlipIndex);
+///
+/// assert_eq!(tuple.current(), &(20, 10));
+/// assert_eq!(tuple.index(), 1);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FlipIndex;
+
+impl<T> SwapStrategy<T> for FlipIndex {
+    fn apply(&self, buffer: &mut DoubleBuffer<T>) {
+        buffer.swap();
+    }
+}
+
+/// Exchanges contents of copies, same as [`DoubleBuffer::swap_contents`]
+///
+/// # Example
+///
+/// ```
+/// use bevy_double_res::{DoubleBuffer, SwapContents};
+/// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+///
+/// tuple.advance_with(&SwapContents);
+///
+/// assert_eq!(tuple.current(), &(20, 10));
+/// assert_eq!(tuple.index(), 0);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SwapContents;
+
+impl<T> SwapStrategy<T> for SwapContents {
+    fn apply(&self, buffer: &mut DoubleBuffer<T>) {
+        buffer.swap_contents();
+    }
+}
+
+/// Flips index, then clones new current copy into next one, same as [`DoubleBuffer::swap`]
+/// followed by [`DoubleBuffer::sync`]
+///
+/// Next update starts from the committed state instead of the one before it
+///
+/// # Example
+///
+/// ```
+/// use bevy_double_res::{CopyForward, DoubleBuffer};
+/// let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+///
+/// tuple.advance_with(&CopyForward);
+///
+/// assert_eq!(tuple.current(), &(20, 10));
+/// assert_eq!(tuple.next(), &(20, 10));
+/// assert_eq!(tuple.index(), 1);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CopyForward;
+
+impl<T> SwapStrategy<T> for CopyForward
+where
+    T: Clone,
+{
+    fn apply(&self, buffer: &mut DoubleBuffer<T>) {
+        buffer.swap();
+        buffer.sync();
+    }
+}
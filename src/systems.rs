@@ -0,0 +1,64 @@
+//! Provides ready-made systems for buffer resources
+
+use crate::{DoubleEvents, DoubleResMut};
+use bevy_ecs::system::ResMut;
+
+/// System swapping [DoubleBuffer<T>](crate::DoubleBuffer) resource
+///
+/// # Example
+///
+/// ```
+/// use bevy::ecs::schedule::Stage;
+/// use bevy::prelude::*;
+/// use bevy_double_res::prelude::*;
+/// use bevy_double_res::systems::swap_double_buffer;
+///
+/// let mut world = World::new();
+/// world.insert_resource(DoubleBuffer::from_buffer([10, 20], 0));
+///
+/// let mut stage = SystemStage::single_threaded().with_system(swap_double_buffer::<i32>);
+///
+/// stage.run(&mut world);
+///
+/// assert_eq!(world.resource::<DoubleBuffer<i32>>().index(), 1);
+/// assert_eq!(world.resource::<DoubleBuffer<i32>>().current(), &20);
+/// ```
+pub fn swap_double_buffer<T>(mut buffer: DoubleResMut<T>)
+where
+    T: Send + Sync + 'static,
+{
+    buffer.swap();
+}
+
+/// System swapping [DoubleEvents<E>] resource
+///
+/// # Example
+///
+/// ```
+/// use bevy::ecs::schedule::Stage;
+/// use bevy::prelude::*;
+/// use bevy_double_res::prelude::*;
+///
+/// let mut world = World::new();
+/// world.insert_resource(DoubleEvents::<u32>::default());
+///
+/// let mut stage = SystemStage::single_threaded().with_system(swap_double_events::<u32>);
+///
+/// world.resource_mut::<DoubleEvents<u32>>().write_next(1);
+///
+/// assert!(world.resource::<DoubleEvents<u32>>().current().is_empty());
+///
+/// stage.run(&mut world);
+///
+/// assert_eq!(world.resource::<DoubleEvents<u32>>().current(), &[1]);
+///
+/// stage.run(&mut world);
+///
+/// assert!(world.resource::<DoubleEvents<u32>>().current().is_empty());
+/// ```
+pub fn swap_double_events<E>(mut events: ResMut<DoubleEvents<E>>)
+where
+    E: Send + Sync + 'static,
+{
+    events.swap();
+}
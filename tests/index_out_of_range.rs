@@ -0,0 +1,49 @@
+//! Out of range index set with `set_index` is reported with readable message in debug builds
+
+#![cfg(debug_assertions)]
+
+use bevy_double_res::DoubleBuffer;
+
+fn corrupted() -> DoubleBuffer<(i32, i32)> {
+    let mut tuple = DoubleBuffer::from_buffer([(10, 20), (20, 10)], 0);
+    tuple.set_index(2);
+    tuple
+}
+
+#[test]
+#[should_panic(expected = "DoubleBuffer index must be 0 or 1, got 2")]
+fn current() {
+    corrupted().current();
+}
+
+#[test]
+#[should_panic(expected = "DoubleBuffer index must be 0 or 1, got 2")]
+fn current_mut() {
+    corrupted().current_mut();
+}
+
+#[test]
+#[should_panic(expected = "DoubleBuffer index must be 0 or 1, got 2")]
+fn next() {
+    corrupted().next();
+}
+
+#[test]
+#[should_panic(expected = "DoubleBuffer index must be 0 or 1, got 2")]
+fn next_mut() {
+    corrupted().next_mut();
+}
+
+#[test]
+#[should_panic(expected = "DoubleBuffer index must be 0 or 1, got 2")]
+fn side() {
+    corrupted().side();
+}
+
+#[test]
+fn debug_format() {
+    assert_eq!(
+        format!("{:?}", corrupted()),
+        "DoubleBuffer { current: None, next: None, index: 2 }"
+    );
+}